@@ -0,0 +1,127 @@
+use crate::error::ClientError;
+use crate::{
+    build_http_client, endpoint, execute, Client, Email, ServerSettings, ACCOUNT_TOKEN_HEADER,
+    DEFAULT_TIMEOUT,
+};
+use reqwest::Url;
+use secrecy::SecretString;
+use serde::de::DeserializeOwned;
+use std::time::Duration;
+
+/// A client for the account-wide APIs, such as servers, domains, sender
+/// signatures and pushing templates between servers. It authenticates with
+/// an account token instead of a server token.
+#[derive(Clone, Debug)]
+pub struct AccountClient {
+    pub(crate) http_client: reqwest::Client,
+    base_url: Url,
+    auth_token: SecretString,
+    timeout: Duration,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct AccountClientBuilder {
+    base_url: Option<Url>,
+    auth_token: Option<SecretString>,
+    timeout: Option<Duration>,
+}
+
+impl AccountClientBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn base_url(mut self, url: Url) -> Self {
+        self.base_url = Some(url);
+        self
+    }
+
+    pub fn auth_token(mut self, token: SecretString) -> Self {
+        self.auth_token = Some(token);
+        self
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn build(self) -> Result<AccountClient, ClientError> {
+        let base_url = self.base_url.ok_or_else(|| {
+            ClientError::Configuration("Postmark base URL is required".to_string())
+        })?;
+        let auth_token = self.auth_token.ok_or_else(|| {
+            ClientError::Configuration("Postmark account token is required".to_string())
+        })?;
+
+        let timeout = self.timeout.unwrap_or(DEFAULT_TIMEOUT);
+
+        let http_client = build_http_client(timeout)?;
+
+        Ok(AccountClient {
+            http_client,
+            base_url,
+            auth_token,
+            timeout,
+        })
+    }
+}
+
+impl AccountClient {
+    pub fn builder() -> AccountClientBuilder {
+        AccountClientBuilder::new()
+    }
+
+    /// Builds a [`Client`] for `server` with its first API token, sharing
+    /// this client's base URL and timeout.
+    pub fn server_client(
+        &self,
+        server: &ServerSettings,
+        sender: Email,
+    ) -> Result<Client, ClientError> {
+        let token = server.api_token().ok_or_else(|| {
+            ClientError::Configuration(format!("Postmark server {} has no API token", server.id))
+        })?;
+
+        Client::builder()
+            .base_url(self.base_url.clone())
+            .sender(sender)
+            .auth_token(token.clone())
+            .timeout(self.timeout)
+            .build()
+    }
+
+    pub(crate) fn endpoint(&self, path: &str) -> Result<Url, ClientError> {
+        endpoint(&self.base_url, path)
+    }
+
+    /// Authenticates the request with the account token, sends it and maps
+    /// the Postmark response onto either `T` or a [`ClientError`].
+    pub(crate) async fn execute<T: DeserializeOwned>(
+        &self,
+        request: reqwest::RequestBuilder,
+    ) -> Result<T, ClientError> {
+        execute(
+            request,
+            ACCOUNT_TOKEN_HEADER,
+            &self.auth_token,
+            self.timeout,
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use claim::assert_err;
+
+    #[test]
+    fn test_builder_requires_an_account_token() {
+        let client = AccountClient::builder()
+            .base_url(Url::parse("https://api.postmarkapp.com").unwrap())
+            .build();
+
+        assert_err!(client);
+    }
+}
@@ -49,9 +49,10 @@ impl AttachmentBuilder {
 
         Ok(Attachment {
             name,
-            content: base64::engine::general_purpose::STANDARD.encode(content),
+            content: Some(base64::engine::general_purpose::STANDARD.encode(content)),
             content_type,
             content_id: self.content_id,
+            content_length: None,
         })
     }
 }
@@ -60,10 +61,14 @@ impl AttachmentBuilder {
 #[serde(rename_all = "PascalCase")]
 pub struct Attachment {
     name: String,
-    content: String,
+    /// Base64 encoded. Postmark leaves it out when listing messages.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    content: Option<String>,
     content_type: String,
     #[serde(rename = "ContentID")]
     content_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    content_length: Option<u64>,
 }
 
 impl Attachment {
@@ -71,6 +76,35 @@ impl Attachment {
         AttachmentBuilder::new()
     }
 
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn content_type(&self) -> &str {
+        &self.content_type
+    }
+
+    pub fn content_id(&self) -> Option<&str> {
+        self.content_id.as_deref()
+    }
+
+    /// The size in bytes Postmark reported for a received attachment.
+    pub fn content_length(&self) -> Option<u64> {
+        self.content_length
+    }
+
+    /// The decoded content of the attachment. Fails for the attachments of a
+    /// listed message, which come without their content.
+    pub fn content(&self) -> Result<Vec<u8>, ClientError> {
+        let content = self.content.as_deref().ok_or_else(|| {
+            ClientError::InvalidAttachment(format!("'{}' has no content", self.name))
+        })?;
+
+        base64::engine::general_purpose::STANDARD
+            .decode(content)
+            .map_err(|err| ClientError::InvalidAttachment(err.to_string()))
+    }
+
     pub fn from_file(name: &str, filename: &str) -> Result<Self, ClientError> {
         let content = fs::read(filename).map_err(ClientError::Io)?;
         let ext = std::path::Path::new(filename)
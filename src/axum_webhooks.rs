@@ -0,0 +1,430 @@
+use crate::error::WebhookRejection;
+use crate::webhook_verifier::parse_payload;
+use crate::webhooks::{
+    Bounce, Click, Delivery, Open, SpamComplaint, SubscriptionChange, WebhookEvent,
+};
+use crate::{InboundWebhook, WebhookVerifier};
+use axum::body::Bytes;
+use axum::extract::{ConnectInfo, DefaultBodyLimit, FromRef, FromRequest, Request, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::routing::post;
+use axum::Router;
+use std::error::Error;
+use std::fmt;
+use std::future::Future;
+use std::net::SocketAddr;
+use std::pin::Pin;
+use std::sync::Arc;
+
+/// Inbound messages can be up to 35 MB, which grows by a third once the
+/// attachments are base64 encoded.
+const BODY_LIMIT: usize = 50 * 1024 * 1024;
+
+/// Extracts a verified outbound webhook event.
+///
+/// The verifier is taken from the state. When it checks the source address,
+/// the peer address comes from the `ConnectInfo<SocketAddr>` the server was
+/// started with.
+#[derive(Debug, Clone)]
+pub struct PostmarkWebhook(pub WebhookEvent);
+
+/// Extracts a verified inbound webhook message.
+#[derive(Debug, Clone)]
+pub struct PostmarkInbound(pub InboundWebhook);
+
+impl IntoResponse for WebhookRejection {
+    fn into_response(self) -> Response {
+        (self.status_code(), self.to_string()).into_response()
+    }
+}
+
+/// Authorizes the request on its headers and source first, so the body of
+/// a request that isn't from Postmark is never read.
+async fn verified_body<S: Send + Sync>(request: Request, state: &S) -> Result<Bytes, Response>
+where
+    WebhookVerifier: FromRef<S>,
+{
+    let verifier = WebhookVerifier::from_ref(state);
+    let peer_addr = request
+        .extensions()
+        .get::<ConnectInfo<SocketAddr>>()
+        .map(|ConnectInfo(addr)| addr.ip());
+
+    if let Err(rejection) = verifier.authorize_parts(request.headers(), peer_addr) {
+        if let WebhookRejection::Misconfigured(reason) = &rejection {
+            tracing::error!(reason, "Postmark webhook route is misconfigured");
+        }
+        return Err(rejection.into_response());
+    }
+
+    Bytes::from_request(request, state)
+        .await
+        .map_err(IntoResponse::into_response)
+}
+
+impl<S: Send + Sync> FromRequest<S> for PostmarkWebhook
+where
+    WebhookVerifier: FromRef<S>,
+{
+    type Rejection = Response;
+
+    async fn from_request(request: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let body = verified_body(request, state).await?;
+
+        parse_payload(&body)
+            .map(PostmarkWebhook)
+            .map_err(IntoResponse::into_response)
+    }
+}
+
+impl<S: Send + Sync> FromRequest<S> for PostmarkInbound
+where
+    WebhookVerifier: FromRef<S>,
+{
+    type Rejection = Response;
+
+    async fn from_request(request: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let body = verified_body(request, state).await?;
+
+        parse_payload(&body)
+            .map(PostmarkInbound)
+            .map_err(IntoResponse::into_response)
+    }
+}
+
+type HandlerResult = Result<(), Box<dyn Error + Send + Sync>>;
+type Handler<T> =
+    Arc<dyn Fn(T) -> Pin<Box<dyn Future<Output = HandlerResult> + Send>> + Send + Sync>;
+
+fn handler<T, F, Fut, E>(f: F) -> Option<Handler<T>>
+where
+    F: Fn(T) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<(), E>> + Send + 'static,
+    E: Into<Box<dyn Error + Send + Sync>>,
+{
+    Some(Arc::new(move |event| {
+        let future = f(event);
+        Box::pin(async move { future.await.map_err(Into::into) })
+    }))
+}
+
+/// Serves Postmark webhooks and hands each verified event to its handler.
+///
+/// The router accepts outbound webhooks on `POST /` and inbound messages on
+/// `POST /inbound`, so it is meant to be nested under the path the webhooks
+/// are configured with. It answers `200 OK` once the handler succeeded, and
+/// `500 Internal Server Error` when it failed so Postmark retries the
+/// webhook later. Events without a handler are acknowledged and dropped.
+///
+/// When the verifier checks the source addresses, the server has to be
+/// started with `into_make_service_with_connect_info::<SocketAddr>()`,
+/// otherwise every request is answered with a 500.
+///
+/// ```no_run
+/// use postmark_client::{SecretString, WebhookRouter, WebhookVerifier};
+/// use std::net::SocketAddr;
+///
+/// # async fn run() -> std::io::Result<()> {
+/// let verifier = WebhookVerifier::new()
+///     .basic_auth("postmark", SecretString::from("secret"))
+///     .allow_postmark_ips();
+/// let webhooks = WebhookRouter::new(verifier)
+///     .on_bounce(|bounce| async move {
+///         println!("{} bounced", bounce.email);
+///         Ok::<_, std::io::Error>(())
+///     })
+///     .on_inbound(|message| async move {
+///         println!("received {}", message.message_id);
+///         Ok::<_, std::io::Error>(())
+///     });
+///
+/// let app = axum::Router::new().nest("/postmark", webhooks.into_router());
+/// let listener = tokio::net::TcpListener::bind("0.0.0.0:3000").await?;
+/// axum::serve(listener, app.into_make_service_with_connect_info::<SocketAddr>()).await
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct WebhookRouter {
+    verifier: WebhookVerifier,
+    delivery: Option<Handler<Delivery>>,
+    bounce: Option<Handler<Bounce>>,
+    spam_complaint: Option<Handler<SpamComplaint>>,
+    open: Option<Handler<Open>>,
+    click: Option<Handler<Click>>,
+    subscription_change: Option<Handler<SubscriptionChange>>,
+    inbound: Option<Handler<InboundWebhook>>,
+}
+
+impl fmt::Debug for WebhookRouter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WebhookRouter")
+            .field("verifier", &self.verifier)
+            .finish_non_exhaustive()
+    }
+}
+
+macro_rules! handler_setter {
+    ($(#[$doc:meta])* $name:ident, $field:ident, $event:ty) => {
+        $(#[$doc])*
+        pub fn $name<F, Fut, E>(mut self, f: F) -> Self
+        where
+            F: Fn($event) -> Fut + Send + Sync + 'static,
+            Fut: Future<Output = Result<(), E>> + Send + 'static,
+            E: Into<Box<dyn Error + Send + Sync>>,
+        {
+            self.$field = handler(f);
+            self
+        }
+    };
+}
+
+impl WebhookRouter {
+    pub fn new(verifier: WebhookVerifier) -> Self {
+        Self {
+            verifier,
+            delivery: None,
+            bounce: None,
+            spam_complaint: None,
+            open: None,
+            click: None,
+            subscription_change: None,
+            inbound: None,
+        }
+    }
+
+    handler_setter!(on_delivery, delivery, Delivery);
+    handler_setter!(on_bounce, bounce, Bounce);
+    handler_setter!(on_spam_complaint, spam_complaint, SpamComplaint);
+    handler_setter!(on_open, open, Open);
+    handler_setter!(on_click, click, Click);
+    handler_setter!(
+        on_subscription_change,
+        subscription_change,
+        SubscriptionChange
+    );
+    handler_setter!(
+        /// Handles the messages posted to the inbound webhook.
+        on_inbound,
+        inbound,
+        InboundWebhook
+    );
+
+    pub fn into_router<S>(self) -> Router<S> {
+        Router::new()
+            .route("/", post(handle_event))
+            .route("/inbound", post(handle_inbound))
+            .layer(DefaultBodyLimit::max(BODY_LIMIT))
+            .with_state(Arc::new(self))
+    }
+
+    async fn dispatch(&self, event: WebhookEvent) -> HandlerResult {
+        match event {
+            WebhookEvent::Delivery(event) => call(&self.delivery, event).await,
+            WebhookEvent::Bounce(event) => call(&self.bounce, event).await,
+            WebhookEvent::SpamComplaint(event) => call(&self.spam_complaint, event).await,
+            WebhookEvent::Open(event) => call(&self.open, event).await,
+            WebhookEvent::Click(event) => call(&self.click, event).await,
+            WebhookEvent::SubscriptionChange(event) => call(&self.subscription_change, event).await,
+            WebhookEvent::Unknown { .. } => Ok(()),
+        }
+    }
+}
+
+impl FromRef<Arc<WebhookRouter>> for WebhookVerifier {
+    fn from_ref(router: &Arc<WebhookRouter>) -> Self {
+        router.verifier.clone()
+    }
+}
+
+async fn call<T>(handler: &Option<Handler<T>>, event: T) -> HandlerResult {
+    match handler {
+        Some(handler) => handler(event).await,
+        None => Ok(()),
+    }
+}
+
+fn respond(record_type: &str, result: HandlerResult) -> StatusCode {
+    match result {
+        Ok(()) => StatusCode::OK,
+        Err(error) => {
+            tracing::error!(record_type, error = %error, "Postmark webhook handler failed");
+            StatusCode::INTERNAL_SERVER_ERROR
+        }
+    }
+}
+
+async fn handle_event(
+    State(router): State<Arc<WebhookRouter>>,
+    PostmarkWebhook(event): PostmarkWebhook,
+) -> StatusCode {
+    let record_type = event.record_type().to_string();
+    respond(&record_type, router.dispatch(event).await)
+}
+
+async fn handle_inbound(
+    State(router): State<Arc<WebhookRouter>>,
+    PostmarkInbound(message): PostmarkInbound,
+) -> StatusCode {
+    respond("Inbound", call(&router.inbound, message).await)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{SecretString, POSTMARK_WEBHOOK_IPS};
+    use axum::body::Body;
+    use base64::engine::general_purpose::STANDARD;
+    use base64::Engine;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use tower::ServiceExt;
+
+    const BOUNCE: &str = r#"{
+        "RecordType": "Bounce",
+        "ID": 42,
+        "Type": "HardBounce",
+        "TypeCode": 1,
+        "Name": "Hard bounce",
+        "Email": "john@example.com",
+        "BouncedAt": "2019-11-05T16:33:54.9070259Z",
+        "DumpAvailable": false,
+        "Inactive": true,
+        "CanActivate": true
+    }"#;
+
+    fn router(fail: bool, calls: Arc<AtomicUsize>) -> Router {
+        let verifier = WebhookVerifier::new()
+            .basic_auth("postmark", SecretString::from("secret"))
+            .allow_postmark_ips();
+
+        WebhookRouter::new(verifier)
+            .on_bounce(move |bounce| {
+                let calls = calls.clone();
+                async move {
+                    assert_eq!(bounce.email, "john@example.com");
+                    calls.fetch_add(1, Ordering::SeqCst);
+                    if fail {
+                        Err("database is down")
+                    } else {
+                        Ok(())
+                    }
+                }
+            })
+            .into_router()
+    }
+
+    fn request(uri: &str, peer: SocketAddr, credentials: &str, body: &'static str) -> Request {
+        let mut request = Request::post(uri)
+            .header(
+                http::header::AUTHORIZATION,
+                format!("Basic {}", STANDARD.encode(credentials)),
+            )
+            .body(Body::from(body))
+            .unwrap();
+        request.extensions_mut().insert(ConnectInfo(peer));
+        request
+    }
+
+    fn postmark_peer() -> SocketAddr {
+        SocketAddr::new(POSTMARK_WEBHOOK_IPS[0], 443)
+    }
+
+    #[tokio::test]
+    async fn test_router_dispatches_to_handler() {
+        let calls = Arc::new(AtomicUsize::new(0));
+
+        let response = router(false, calls.clone())
+            .oneshot(request("/", postmark_peer(), "postmark:secret", BOUNCE))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_router_fails_when_handler_fails() {
+        let calls = Arc::new(AtomicUsize::new(0));
+
+        let response = router(true, calls.clone())
+            .oneshot(request("/", postmark_peer(), "postmark:secret", BOUNCE))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_router_rejects_unverified_requests() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let stranger = "192.168.1.1:443".parse().unwrap();
+
+        let wrong_password = router(false, calls.clone())
+            .oneshot(request("/", postmark_peer(), "postmark:guess", BOUNCE))
+            .await
+            .unwrap();
+        let wrong_source = router(false, calls.clone())
+            .oneshot(request("/", stranger, "postmark:secret", BOUNCE))
+            .await
+            .unwrap();
+
+        assert_eq!(wrong_password.status(), StatusCode::UNAUTHORIZED);
+        assert_eq!(wrong_source.status(), StatusCode::FORBIDDEN);
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn test_router_rejects_before_reading_the_body() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let mut oversized = Request::post("/")
+            .body(Body::from(vec![b' '; BODY_LIMIT + 1]))
+            .unwrap();
+        oversized
+            .extensions_mut()
+            .insert(ConnectInfo(postmark_peer()));
+
+        let response = router(false, calls).oneshot(oversized).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn test_router_needs_connect_info_only_to_check_sources() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let auth_only = WebhookRouter::new(
+            WebhookVerifier::new().basic_auth("postmark", SecretString::from("secret")),
+        )
+        .into_router();
+        let without_connect_info = || {
+            Request::post("/")
+                .header(
+                    http::header::AUTHORIZATION,
+                    format!("Basic {}", STANDARD.encode("postmark:secret")),
+                )
+                .body(Body::from(BOUNCE))
+                .unwrap()
+        };
+
+        let accepted = auth_only.oneshot(without_connect_info()).await.unwrap();
+        let misconfigured = router(false, calls)
+            .oneshot(without_connect_info())
+            .await
+            .unwrap();
+
+        assert_eq!(accepted.status(), StatusCode::OK);
+        assert_eq!(misconfigured.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    }
+
+    #[tokio::test]
+    async fn test_router_acknowledges_events_without_handler() {
+        let body = r#"{"RecordType": "Inbound", "FromFull": {"Email": "jane@example.com"}}"#;
+
+        let response = router(false, Arc::new(AtomicUsize::new(0)))
+            .oneshot(request("/", postmark_peer(), "postmark:secret", body))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+}
@@ -0,0 +1,359 @@
+use crate::error::ClientError;
+use crate::Client;
+use serde::{Deserialize, Serialize};
+
+/// Every bounce type Postmark reports.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum BounceType {
+    HardBounce,
+    Transient,
+    Unsubscribe,
+    Subscribe,
+    AutoResponder,
+    AddressChange,
+    DnsError,
+    SpamNotification,
+    OpenRelayTest,
+    Unknown,
+    SoftBounce,
+    VirusNotification,
+    ChallengeVerification,
+    BadEmailAddress,
+    SpamComplaint,
+    ManuallyDeactivated,
+    Unconfirmed,
+    Blocked,
+    #[serde(rename = "SMTPApiError")]
+    SmtpApiError,
+    InboundError,
+    #[serde(rename = "DMARCPolicy")]
+    DmarcPolicy,
+    TemplateRenderingFailed,
+    /// A type Postmark added after this client, named as reported.
+    #[serde(untagged)]
+    Other(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Bounce {
+    #[serde(rename = "ID")]
+    pub id: i64,
+    #[serde(rename = "Type")]
+    pub bounce_type: BounceType,
+    pub type_code: i64,
+    pub name: String,
+    pub tag: Option<String>,
+    #[serde(rename = "MessageID")]
+    pub message_id: Option<String>,
+    #[serde(rename = "ServerID")]
+    pub server_id: Option<i64>,
+    pub message_stream: Option<String>,
+    pub description: Option<String>,
+    pub details: Option<String>,
+    pub email: String,
+    pub from: Option<String>,
+    pub bounced_at: String,
+    pub dump_available: bool,
+    pub inactive: bool,
+    pub can_activate: bool,
+    pub subject: Option<String>,
+    /// The raw bounce message. Only returned when fetching a single bounce.
+    pub content: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct BounceList {
+    pub total_count: u32,
+    pub bounces: Vec<Bounce>,
+}
+
+/// The raw source of a bounce. `body` is empty once Postmark no longer
+/// keeps the dump, see [`Bounce::dump_available`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct BounceDump {
+    #[serde(default)]
+    pub body: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct BounceActivation {
+    pub message: String,
+    pub bounce: Bounce,
+}
+
+/// Bounce counts for the server, as returned by `GET /deliverystats`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct DeliveryStats {
+    pub inactive_mails: u32,
+    pub bounces: Vec<BounceCount>,
+}
+
+/// The number of bounces of one type. The `All` entry Postmark includes
+/// has no `bounce_type`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct BounceCount {
+    #[serde(rename = "Type")]
+    pub bounce_type: Option<BounceType>,
+    pub name: String,
+    pub count: u32,
+}
+
+impl DeliveryStats {
+    /// The number of bounces across all types.
+    pub fn total(&self) -> u32 {
+        self.bounces
+            .iter()
+            .find(|bounce| bounce.bounce_type.is_none())
+            .map(|bounce| bounce.count)
+            .unwrap_or_else(|| self.bounces.iter().map(|bounce| bounce.count).sum())
+    }
+
+    /// The number of bounces of `bounce_type`, zero when none were reported.
+    pub fn count(&self, bounce_type: BounceType) -> u32 {
+        self.bounces
+            .iter()
+            .find(|bounce| bounce.bounce_type.as_ref() == Some(&bounce_type))
+            .map_or(0, |bounce| bounce.count)
+    }
+}
+
+/// Query parameters for `GET /bounces`.
+#[derive(Debug, Clone, Serialize)]
+pub struct BounceFilter {
+    count: u32,
+    offset: u32,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    bounce_type: Option<BounceType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    inactive: Option<bool>,
+    #[serde(rename = "emailFilter", skip_serializing_if = "Option::is_none")]
+    email_filter: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tag: Option<String>,
+    #[serde(rename = "messageID", skip_serializing_if = "Option::is_none")]
+    message_id: Option<String>,
+    #[serde(rename = "fromdate", skip_serializing_if = "Option::is_none")]
+    from_date: Option<String>,
+    #[serde(rename = "todate", skip_serializing_if = "Option::is_none")]
+    to_date: Option<String>,
+    #[serde(rename = "messagestream", skip_serializing_if = "Option::is_none")]
+    message_stream: Option<String>,
+}
+
+impl Default for BounceFilter {
+    fn default() -> Self {
+        Self {
+            count: 100,
+            offset: 0,
+            bounce_type: None,
+            inactive: None,
+            email_filter: None,
+            tag: None,
+            message_id: None,
+            from_date: None,
+            to_date: None,
+            message_stream: None,
+        }
+    }
+}
+
+impl BounceFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn count(mut self, count: u32) -> Self {
+        self.count = count;
+        self
+    }
+
+    pub fn offset(mut self, offset: u32) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    pub fn bounce_type(mut self, bounce_type: BounceType) -> Self {
+        self.bounce_type = Some(bounce_type);
+        self
+    }
+
+    pub fn inactive(mut self, inactive: bool) -> Self {
+        self.inactive = Some(inactive);
+        self
+    }
+
+    /// Only bounces whose address contains `email`.
+    pub fn email_filter(mut self, email: impl Into<String>) -> Self {
+        self.email_filter = Some(email.into());
+        self
+    }
+
+    pub fn tag(mut self, tag: impl Into<String>) -> Self {
+        self.tag = Some(tag.into());
+        self
+    }
+
+    pub fn message_id(mut self, message_id: impl Into<String>) -> Self {
+        self.message_id = Some(message_id.into());
+        self
+    }
+
+    /// Only bounces from this date on, e.g. `2024-01-31`.
+    pub fn from_date(mut self, from_date: impl Into<String>) -> Self {
+        self.from_date = Some(from_date.into());
+        self
+    }
+
+    /// Only bounces up to this date, e.g. `2024-02-29`.
+    pub fn to_date(mut self, to_date: impl Into<String>) -> Self {
+        self.to_date = Some(to_date.into());
+        self
+    }
+
+    pub fn message_stream(mut self, message_stream: impl Into<String>) -> Self {
+        self.message_stream = Some(message_stream.into());
+        self
+    }
+}
+
+impl Client {
+    #[tracing::instrument(name = "Get delivery stats using postmark client", skip(self))]
+    pub async fn delivery_stats(&self) -> Result<DeliveryStats, ClientError> {
+        let request = self.http_client.get(self.endpoint("/deliverystats")?);
+
+        self.execute(request).await
+    }
+
+    #[tracing::instrument(name = "List bounces using postmark client", skip(self))]
+    pub async fn list_bounces(&self, filter: &BounceFilter) -> Result<BounceList, ClientError> {
+        let request = self
+            .http_client
+            .get(self.endpoint("/bounces")?)
+            .query(filter);
+
+        self.execute(request).await
+    }
+
+    #[tracing::instrument(name = "Get bounce using postmark client", skip(self))]
+    pub async fn get_bounce(&self, bounce_id: i64) -> Result<Bounce, ClientError> {
+        let request = self
+            .http_client
+            .get(self.endpoint(&format!("/bounces/{bounce_id}"))?);
+
+        self.execute(request).await
+    }
+
+    #[tracing::instrument(name = "Get bounce dump using postmark client", skip(self))]
+    pub async fn get_bounce_dump(&self, bounce_id: i64) -> Result<BounceDump, ClientError> {
+        let request = self
+            .http_client
+            .get(self.endpoint(&format!("/bounces/{bounce_id}/dump"))?);
+
+        self.execute(request).await
+    }
+
+    /// Reactivates the address of a bounce so Postmark sends to it again.
+    #[tracing::instrument(name = "Activate bounce using postmark client", skip(self))]
+    pub async fn activate_bounce(&self, bounce_id: i64) -> Result<BounceActivation, ClientError> {
+        let request = self
+            .http_client
+            .put(self.endpoint(&format!("/bounces/{bounce_id}/activate"))?);
+
+        self.execute(request).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_bounce_deserialization() {
+        let bounce: Bounce = serde_json::from_value(json!({
+            "ID": 692560173,
+            "Type": "HardBounce",
+            "TypeCode": 1,
+            "Name": "Hard bounce",
+            "Tag": "Invitation",
+            "MessageID": "2c1b63fe-43f2-4db5-91b0-8bdfa44a9316",
+            "ServerID": 23,
+            "MessageStream": "outbound",
+            "Description": "The server was unable to deliver your message (ex: unknown user, mailbox not found).",
+            "Details": "action: failed\r\n",
+            "Email": "anything@blackhole.postmarkapp.com",
+            "From": "sender@postmarkapp.com",
+            "BouncedAt": "2014-01-15T16:09:19.6421112-05:00",
+            "DumpAvailable": false,
+            "Inactive": false,
+            "CanActivate": true,
+            "Subject": "SC API5 Test"
+        }))
+        .unwrap();
+
+        assert_eq!(bounce.id, 692560173);
+        assert_eq!(bounce.bounce_type, BounceType::HardBounce);
+        assert_eq!(bounce.server_id, Some(23));
+        assert_eq!(bounce.content, None);
+    }
+
+    #[test]
+    fn test_delivery_stats_breakdown() {
+        let stats: DeliveryStats = serde_json::from_value(json!({
+            "InactiveMails": 192,
+            "Bounces": [
+                { "Name": "All", "Count": 253 },
+                { "Type": "HardBounce", "Name": "Hard bounce", "Count": 195 },
+                { "Type": "Transient", "Name": "Message delayed", "Count": 10 },
+                { "Type": "SpamComplaint", "Name": "Spam complaint", "Count": 48 }
+            ]
+        }))
+        .unwrap();
+
+        assert_eq!(stats.inactive_mails, 192);
+        assert_eq!(stats.total(), 253);
+        assert_eq!(stats.count(BounceType::HardBounce), 195);
+        assert_eq!(stats.count(BounceType::SoftBounce), 0);
+    }
+
+    #[test]
+    fn test_bounce_type_uses_postmark_names() {
+        let types: Vec<BounceType> =
+            serde_json::from_value(json!(["SMTPApiError", "DMARCPolicy", "SpamComplaint"]))
+                .unwrap();
+
+        assert_eq!(
+            types,
+            vec![
+                BounceType::SmtpApiError,
+                BounceType::DmarcPolicy,
+                BounceType::SpamComplaint
+            ]
+        );
+    }
+
+    #[test]
+    fn test_new_bounce_types_keep_their_name() {
+        let stats: DeliveryStats = serde_json::from_value(json!({
+            "InactiveMails": 0,
+            "Bounces": [
+                { "Type": "Unknown", "Name": "Unknown", "Count": 3 },
+                { "Type": "NewBounceType", "Name": "New bounce type", "Count": 2 }
+            ]
+        }))
+        .unwrap();
+
+        assert_eq!(stats.count(BounceType::Unknown), 3);
+        let new_type = BounceType::Other("NewBounceType".to_string());
+        assert_eq!(stats.count(new_type.clone()), 2);
+
+        let filter = BounceFilter::new().bounce_type(new_type);
+        assert_eq!(serde_json::to_value(&filter).unwrap()["type"], "NewBounceType");
+    }
+}
@@ -0,0 +1,439 @@
+use crate::error::ClientError;
+use crate::{AccountClient, ApiResponse, Email};
+use serde::{Deserialize, Serialize};
+
+/// A domain as listed by `GET /domains`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DomainSummary {
+    #[serde(rename = "ID")]
+    pub id: i64,
+    #[serde(rename = "Name")]
+    pub name: String,
+    #[serde(rename = "SPFVerified")]
+    pub spf_verified: bool,
+    #[serde(rename = "DKIMVerified")]
+    pub dkim_verified: bool,
+    #[serde(rename = "WeakDKIM")]
+    pub weak_dkim: bool,
+    #[serde(rename = "ReturnPathDomainVerified")]
+    pub return_path_domain_verified: bool,
+}
+
+impl DomainSummary {
+    /// Whether mail can be sent from any address of this domain or of its
+    /// subdomains, which Postmark allows once its DKIM record is verified.
+    pub fn covers(&self, email: &Email) -> bool {
+        let domain = email.domain().to_ascii_lowercase();
+        let name = self.name.to_ascii_lowercase();
+
+        self.dkim_verified
+            && (domain == name
+                || domain
+                    .strip_suffix(&name)
+                    .is_some_and(|subdomain| subdomain.ends_with('.')))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct DomainList {
+    pub total_count: u32,
+    pub domains: Vec<DomainSummary>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DkimUpdateStatus {
+    Pending,
+    Verified,
+    #[serde(other)]
+    Unknown,
+}
+
+/// A domain with its DNS records, as returned by `GET /domains/{id}`.
+///
+/// Postmark leaves the host and value of records that don't apply null or
+/// empty, e.g. the pending DKIM record once the key is verified.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Domain {
+    #[serde(rename = "ID")]
+    pub id: i64,
+    #[serde(rename = "Name")]
+    pub name: String,
+    #[serde(rename = "SPFVerified", default)]
+    pub spf_verified: bool,
+    #[serde(rename = "SPFHost")]
+    pub spf_host: Option<String>,
+    #[serde(rename = "SPFTextValue")]
+    pub spf_text_value: Option<String>,
+    #[serde(rename = "DKIMVerified")]
+    pub dkim_verified: bool,
+    #[serde(rename = "WeakDKIM", default)]
+    pub weak_dkim: bool,
+    #[serde(rename = "DKIMHost")]
+    pub dkim_host: Option<String>,
+    #[serde(rename = "DKIMTextValue")]
+    pub dkim_text_value: Option<String>,
+    /// The new DKIM record, set until it is verified.
+    #[serde(rename = "DKIMPendingHost")]
+    pub dkim_pending_host: Option<String>,
+    #[serde(rename = "DKIMPendingTextValue")]
+    pub dkim_pending_text_value: Option<String>,
+    /// The record of the key replaced by a rotation.
+    #[serde(rename = "DKIMRevokedHost")]
+    pub dkim_revoked_host: Option<String>,
+    #[serde(rename = "DKIMRevokedTextValue")]
+    pub dkim_revoked_text_value: Option<String>,
+    #[serde(rename = "SafeToRemoveRevokedKeyFromDNS", default)]
+    pub safe_to_remove_revoked_key_from_dns: bool,
+    #[serde(rename = "DKIMUpdateStatus")]
+    pub dkim_update_status: Option<DkimUpdateStatus>,
+    #[serde(rename = "ReturnPathDomain")]
+    pub return_path_domain: Option<String>,
+    #[serde(rename = "ReturnPathDomainVerified", default)]
+    pub return_path_domain_verified: bool,
+    #[serde(rename = "ReturnPathDomainCNAMEValue")]
+    pub return_path_domain_cname_value: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DnsRecordType {
+    Txt,
+    Cname,
+}
+
+/// A DNS record to add for a domain.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DnsRecord {
+    pub record_type: DnsRecordType,
+    pub host: String,
+    pub value: String,
+    pub verified: bool,
+}
+
+impl Domain {
+    /// The DKIM record to publish: the pending one while a new key awaits
+    /// verification, otherwise the active one.
+    pub fn dkim_record(&self) -> Option<DnsRecord> {
+        record(
+            DnsRecordType::Txt,
+            &self.dkim_pending_host,
+            &self.dkim_pending_text_value,
+            false,
+        )
+        .or_else(|| {
+            record(
+                DnsRecordType::Txt,
+                &self.dkim_host,
+                &self.dkim_text_value,
+                self.dkim_verified,
+            )
+        })
+    }
+
+    pub fn return_path_record(&self) -> Option<DnsRecord> {
+        record(
+            DnsRecordType::Cname,
+            &self.return_path_domain,
+            &self.return_path_domain_cname_value,
+            self.return_path_domain_verified,
+        )
+    }
+}
+
+fn record(
+    record_type: DnsRecordType,
+    host: &Option<String>,
+    value: &Option<String>,
+    verified: bool,
+) -> Option<DnsRecord> {
+    let host = host.as_deref().filter(|host| !host.is_empty())?;
+    let value = value.as_deref().filter(|value| !value.is_empty())?;
+
+    Some(DnsRecord {
+        record_type,
+        host: host.to_string(),
+        value: value.to_string(),
+        verified,
+    })
+}
+
+/// Query parameters for `GET /domains`.
+#[derive(Debug, Clone, Serialize)]
+pub struct DomainFilter {
+    count: u32,
+    offset: u32,
+}
+
+impl Default for DomainFilter {
+    fn default() -> Self {
+        Self {
+            count: 100,
+            offset: 0,
+        }
+    }
+}
+
+impl DomainFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn count(mut self, count: u32) -> Self {
+        self.count = count;
+        self
+    }
+
+    pub fn offset(mut self, offset: u32) -> Self {
+        self.offset = offset;
+        self
+    }
+}
+
+/// The fields sent when creating or editing a domain. The name can only be
+/// set on creation; fields left unset are omitted.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct DomainBody {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) return_path_domain: Option<String>,
+}
+
+impl DomainBody {
+    pub fn builder() -> DomainBodyBuilder {
+        DomainBodyBuilder::new()
+    }
+}
+
+// The builder for DomainBody
+#[derive(Debug, Clone, Default)]
+pub struct DomainBodyBuilder {
+    body: DomainBody,
+}
+
+impl DomainBodyBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.body.name = Some(name.into());
+        self
+    }
+
+    /// A subdomain of the domain, e.g. `pm-bounces.example.com`, pointed to
+    /// Postmark with a CNAME record.
+    pub fn return_path_domain(mut self, return_path_domain: impl Into<String>) -> Self {
+        self.body.return_path_domain = Some(return_path_domain.into());
+        self
+    }
+
+    pub fn build(self) -> DomainBody {
+        self.body
+    }
+}
+
+impl AccountClient {
+    #[tracing::instrument(name = "List domains using postmark client", skip(self))]
+    pub async fn list_domains(&self, filter: &DomainFilter) -> Result<DomainList, ClientError> {
+        let request = self
+            .http_client
+            .get(self.endpoint("/domains")?)
+            .query(filter);
+
+        self.execute(request).await
+    }
+
+    #[tracing::instrument(name = "Get domain using postmark client", skip(self))]
+    pub async fn get_domain(&self, domain_id: i64) -> Result<Domain, ClientError> {
+        let request = self
+            .http_client
+            .get(self.endpoint(&format!("/domains/{domain_id}"))?);
+
+        self.execute(request).await
+    }
+
+    #[tracing::instrument(name = "Create domain using postmark client", skip(self, body))]
+    pub async fn create_domain(&self, body: &DomainBody) -> Result<Domain, ClientError> {
+        let request = self.http_client.post(self.endpoint("/domains")?).json(body);
+
+        self.execute(request).await
+    }
+
+    #[tracing::instrument(name = "Edit domain using postmark client", skip(self, body))]
+    pub async fn edit_domain(
+        &self,
+        domain_id: i64,
+        body: &DomainBody,
+    ) -> Result<Domain, ClientError> {
+        let request = self
+            .http_client
+            .put(self.endpoint(&format!("/domains/{domain_id}"))?)
+            .json(body);
+
+        self.execute(request).await
+    }
+
+    #[tracing::instrument(name = "Delete domain using postmark client", skip(self))]
+    pub async fn delete_domain(&self, domain_id: i64) -> Result<ApiResponse, ClientError> {
+        let request = self
+            .http_client
+            .delete(self.endpoint(&format!("/domains/{domain_id}"))?);
+
+        self.execute(request).await
+    }
+
+    /// Checks the DKIM record of the domain again.
+    #[tracing::instrument(name = "Verify domain DKIM using postmark client", skip(self))]
+    pub async fn verify_domain_dkim(&self, domain_id: i64) -> Result<Domain, ClientError> {
+        let request = self
+            .http_client
+            .put(self.endpoint(&format!("/domains/{domain_id}/verifyDkim"))?);
+
+        self.execute(request).await
+    }
+
+    /// Checks the Return-Path CNAME record of the domain again.
+    #[tracing::instrument(name = "Verify domain Return-Path using postmark client", skip(self))]
+    pub async fn verify_domain_return_path(&self, domain_id: i64) -> Result<Domain, ClientError> {
+        let request = self
+            .http_client
+            .put(self.endpoint(&format!("/domains/{domain_id}/verifyReturnPath"))?);
+
+        self.execute(request).await
+    }
+
+    /// Creates a new DKIM key. The new record is pending until verified,
+    /// and the old key keeps signing until then.
+    #[tracing::instrument(name = "Rotate domain DKIM using postmark client", skip(self))]
+    pub async fn rotate_domain_dkim(&self, domain_id: i64) -> Result<Domain, ClientError> {
+        let request = self
+            .http_client
+            .post(self.endpoint(&format!("/domains/{domain_id}/rotatedkim"))?);
+
+        self.execute(request).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn domain(name: &str, dkim_verified: bool) -> DomainSummary {
+        DomainSummary {
+            id: 1,
+            name: name.to_string(),
+            spf_verified: false,
+            dkim_verified,
+            weak_dkim: false,
+            return_path_domain_verified: false,
+        }
+    }
+
+    #[test]
+    fn test_domain_records() {
+        let domain: Domain = serde_json::from_value(json!({
+            "Name": "postmarkapp.com",
+            "SPFVerified": true,
+            "SPFHost": "postmarkapp.com",
+            "SPFTextValue": "v=spf1 a mx include:spf.mtasv.net ~all",
+            "DKIMVerified": false,
+            "WeakDKIM": false,
+            "DKIMHost": "",
+            "DKIMTextValue": "",
+            "DKIMPendingHost": "20131031155228pm._domainkey.postmarkapp.com",
+            "DKIMPendingTextValue": "k=rsa;p=MIGfMA0GCSqGSIb3DQEBAQUAA4GNADCBiQKBgQCFn",
+            "DKIMRevokedHost": "",
+            "DKIMRevokedTextValue": "",
+            "SafeToRemoveRevokedKeyFromDNS": false,
+            "DKIMUpdateStatus": "Pending",
+            "ReturnPathDomain": "pmbounces.postmarkapp.com",
+            "ReturnPathDomainVerified": false,
+            "ReturnPathDomainCNAMEValue": "pm.mtasv.net",
+            "ID": 36736
+        }))
+        .unwrap();
+
+        let dkim = domain.dkim_record().unwrap();
+        let return_path = domain.return_path_record().unwrap();
+
+        assert_eq!(domain.dkim_update_status, Some(DkimUpdateStatus::Pending));
+        assert_eq!(dkim.record_type, DnsRecordType::Txt);
+        assert_eq!(dkim.host, "20131031155228pm._domainkey.postmarkapp.com");
+        assert!(!dkim.verified);
+        assert_eq!(return_path.record_type, DnsRecordType::Cname);
+        assert_eq!(return_path.value, "pm.mtasv.net");
+    }
+
+    #[test]
+    fn test_rotated_domain_without_return_path() {
+        let domain: Domain = serde_json::from_value(json!({
+            "Name": "example.com",
+            "DKIMVerified": true,
+            "WeakDKIM": false,
+            "DKIMHost": "20160921046319pm._domainkey.example.com",
+            "DKIMTextValue": "k=rsa;p=MIGfMA0GCSqGSIb3DQEBAQUAA4GNADCBiQKBgQC",
+            "DKIMPendingHost": "",
+            "DKIMPendingTextValue": "",
+            "DKIMRevokedHost": "",
+            "DKIMRevokedTextValue": "",
+            "SafeToRemoveRevokedKeyFromDNS": false,
+            "DKIMUpdateStatus": "Verified",
+            "ID": 64
+        }))
+        .unwrap();
+
+        assert!(domain.dkim_record().unwrap().verified);
+        assert_eq!(domain.return_path_record(), None);
+    }
+
+    #[test]
+    fn test_domain_with_null_records() {
+        let domain: Domain = serde_json::from_value(json!({
+            "Name": "example.com",
+            "SPFVerified": false,
+            "SPFHost": null,
+            "SPFTextValue": null,
+            "DKIMVerified": false,
+            "WeakDKIM": false,
+            "DKIMHost": null,
+            "DKIMTextValue": null,
+            "DKIMPendingHost": "20240101000000pm._domainkey.example.com",
+            "DKIMPendingTextValue": "k=rsa;p=MIGfMA0GCSqGSIb3DQEBAQUAA4GNADCBiQKBgQC",
+            "DKIMRevokedHost": null,
+            "DKIMRevokedTextValue": null,
+            "SafeToRemoveRevokedKeyFromDNS": false,
+            "DKIMUpdateStatus": "Pending",
+            "ReturnPathDomain": null,
+            "ReturnPathDomainVerified": false,
+            "ReturnPathDomainCNAMEValue": null,
+            "ID": 65
+        }))
+        .unwrap();
+
+        assert_eq!(domain.dkim_host, None);
+        assert!(!domain.dkim_record().unwrap().verified);
+        assert_eq!(domain.return_path_record(), None);
+    }
+
+    #[test]
+    fn test_only_verified_domains_cover_their_addresses() {
+        let sender = Email::parse("jp@example.com").unwrap();
+
+        assert!(domain("example.com", true).covers(&sender));
+        assert!(!domain("example.com", false).covers(&sender));
+        assert!(!domain("mail.example.com", true).covers(&sender));
+    }
+
+    #[test]
+    fn test_verified_domains_cover_their_subdomains() {
+        let sender = Email::parse("jp@mail.Example.com").unwrap();
+
+        assert!(domain("example.com", true).covers(&sender));
+        assert!(domain("mail.example.com", true).covers(&sender));
+        assert!(!domain("example.com", false).covers(&sender));
+        assert!(!domain("ample.com", true).covers(&sender));
+    }
+}
@@ -48,6 +48,11 @@ impl Email {
         Email(email)
     }
 
+    /// The part of the address after the `@`.
+    pub fn domain(&self) -> &str {
+        self.0.rsplit_once('@').map_or("", |(_, domain)| domain)
+    }
+
     pub fn hash(&self) -> String {
         let mut hasher = blake3::Hasher::new();
         hasher.update(self.0.as_bytes());
@@ -55,6 +60,25 @@ impl Email {
     }
 }
 
+/// An address together with its display name, as Postmark reports the
+/// recipients and senders of messages.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct NamedAddress {
+    pub email: String,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mailbox_hash: Option<String>,
+}
+
+impl NamedAddress {
+    /// Validates the address into an [`Email`].
+    pub fn to_email(&self) -> Result<Email, ParseError> {
+        Email::parse(&self.email)
+    }
+}
+
 impl AsRef<str> for Email {
     fn as_ref(&self) -> &str {
         self.0.as_str()
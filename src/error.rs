@@ -1,4 +1,5 @@
 use reqwest::StatusCode;
+use std::net::IpAddr;
 
 #[derive(Debug, thiserror::Error)]
 pub enum ClientError {
@@ -26,6 +27,9 @@ pub enum ClientError {
     #[error("Invalid email attachment: {0}")]
     InvalidAttachment(String),
 
+    #[error("Template rendering failed: {0}")]
+    TemplateRender(String),
+
     #[error("Operation timed out after {0} seconds")]
     Timeout(u64),
 }
@@ -33,3 +37,36 @@ pub enum ClientError {
 #[derive(Debug, thiserror::Error)]
 #[error("{0}")]
 pub struct ParseError(pub String);
+
+/// Why a webhook request was not accepted.
+#[derive(Debug, thiserror::Error)]
+pub enum WebhookRejection {
+    #[error("Webhook request has no basic auth credentials")]
+    MissingCredentials,
+
+    #[error("Webhook request has invalid basic auth credentials")]
+    InvalidCredentials,
+
+    #[error("Webhook request came from a source that is not allowed: {0}")]
+    ForbiddenSource(IpAddr),
+
+    #[error("Webhook payload is invalid: {0}")]
+    InvalidPayload(#[source] serde_json::Error),
+
+    #[error("Webhook verifier is misconfigured: {0}")]
+    Misconfigured(&'static str),
+}
+
+impl WebhookRejection {
+    /// The status code to answer the request with.
+    pub fn status_code(&self) -> StatusCode {
+        match self {
+            WebhookRejection::MissingCredentials | WebhookRejection::InvalidCredentials => {
+                StatusCode::UNAUTHORIZED
+            }
+            WebhookRejection::ForbiddenSource(_) => StatusCode::FORBIDDEN,
+            WebhookRejection::InvalidPayload(_) => StatusCode::BAD_REQUEST,
+            WebhookRejection::Misconfigured(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
@@ -0,0 +1,319 @@
+use crate::error::ClientError;
+use crate::{path_segment, ApiResponse, Attachment, Client, NamedAddress};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum InboundMessageStatus {
+    #[serde(rename = "blocked", alias = "Blocked")]
+    Blocked,
+    #[serde(rename = "processed", alias = "Processed")]
+    Processed,
+    #[serde(rename = "queued", alias = "Queued")]
+    Queued,
+    #[serde(rename = "failed", alias = "Failed")]
+    Failed,
+    #[serde(rename = "scheduled", alias = "Scheduled")]
+    Scheduled,
+    /// A status this client does not know about yet. It can't be used as a
+    /// filter.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+/// A raw header of a received message.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct MessageHeader {
+    pub name: String,
+    pub value: String,
+}
+
+/// An inbound message as returned by `GET /messages/inbound`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct InboundMessage {
+    #[serde(rename = "MessageID")]
+    pub message_id: String,
+    pub from: String,
+    pub from_name: Option<String>,
+    pub from_full: NamedAddress,
+    pub to: Option<String>,
+    #[serde(default)]
+    pub to_full: Vec<NamedAddress>,
+    pub cc: Option<String>,
+    #[serde(default)]
+    pub cc_full: Vec<NamedAddress>,
+    pub reply_to: Option<String>,
+    pub original_recipient: Option<String>,
+    pub subject: Option<String>,
+    pub date: String,
+    pub mailbox_hash: Option<String>,
+    pub tag: Option<String>,
+    pub status: InboundMessageStatus,
+    /// The attachments of the message. Their content is only included in
+    /// the message details.
+    #[serde(default)]
+    pub attachments: Vec<Attachment>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct InboundMessageList {
+    pub total_count: u32,
+    pub inbound_messages: Vec<InboundMessage>,
+}
+
+/// An inbound message with its content and headers, as returned by
+/// `GET /messages/inbound/{messageid}/details`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct InboundMessageDetails {
+    #[serde(flatten)]
+    pub message: InboundMessage,
+    pub text_body: Option<String>,
+    pub html_body: Option<String>,
+    #[serde(default)]
+    pub headers: Vec<MessageHeader>,
+    pub blocked_reason: Option<String>,
+    pub message_stream: Option<String>,
+}
+
+impl InboundMessageDetails {
+    /// The value of the first header called `name`, ignoring case.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|header| header.name.eq_ignore_ascii_case(name))
+            .map(|header| header.value.as_str())
+    }
+}
+
+/// Query parameters for `GET /messages/inbound`.
+#[derive(Debug, Clone, Serialize)]
+pub struct InboundMessageFilter {
+    count: u32,
+    offset: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    recipient: Option<String>,
+    #[serde(rename = "fromemail", skip_serializing_if = "Option::is_none")]
+    from_email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tag: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    subject: Option<String>,
+    #[serde(rename = "mailboxhash", skip_serializing_if = "Option::is_none")]
+    mailbox_hash: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status: Option<InboundMessageStatus>,
+    #[serde(rename = "fromdate", skip_serializing_if = "Option::is_none")]
+    from_date: Option<String>,
+    #[serde(rename = "todate", skip_serializing_if = "Option::is_none")]
+    to_date: Option<String>,
+}
+
+impl Default for InboundMessageFilter {
+    fn default() -> Self {
+        Self {
+            count: 100,
+            offset: 0,
+            recipient: None,
+            from_email: None,
+            tag: None,
+            subject: None,
+            mailbox_hash: None,
+            status: None,
+            from_date: None,
+            to_date: None,
+        }
+    }
+}
+
+impl InboundMessageFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn count(mut self, count: u32) -> Self {
+        self.count = count;
+        self
+    }
+
+    pub fn offset(mut self, offset: u32) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    pub fn recipient(mut self, recipient: impl Into<String>) -> Self {
+        self.recipient = Some(recipient.into());
+        self
+    }
+
+    pub fn from_email(mut self, from_email: impl Into<String>) -> Self {
+        self.from_email = Some(from_email.into());
+        self
+    }
+
+    pub fn tag(mut self, tag: impl Into<String>) -> Self {
+        self.tag = Some(tag.into());
+        self
+    }
+
+    pub fn subject(mut self, subject: impl Into<String>) -> Self {
+        self.subject = Some(subject.into());
+        self
+    }
+
+    pub fn mailbox_hash(mut self, mailbox_hash: impl Into<String>) -> Self {
+        self.mailbox_hash = Some(mailbox_hash.into());
+        self
+    }
+
+    pub fn status(mut self, status: InboundMessageStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Only messages from this date on, e.g. `2024-01-31`.
+    pub fn from_date(mut self, from_date: impl Into<String>) -> Self {
+        self.from_date = Some(from_date.into());
+        self
+    }
+
+    /// Only messages up to this date, e.g. `2024-02-29`.
+    pub fn to_date(mut self, to_date: impl Into<String>) -> Self {
+        self.to_date = Some(to_date.into());
+        self
+    }
+}
+
+impl Client {
+    #[tracing::instrument(name = "Search inbound messages using postmark client", skip(self))]
+    pub async fn search_inbound_messages(
+        &self,
+        filter: &InboundMessageFilter,
+    ) -> Result<InboundMessageList, ClientError> {
+        let request = self
+            .http_client
+            .get(self.endpoint("/messages/inbound")?)
+            .query(filter);
+
+        self.execute(request).await
+    }
+
+    #[tracing::instrument(name = "Get inbound message details using postmark client", skip(self))]
+    pub async fn get_inbound_message(
+        &self,
+        message_id: &str,
+    ) -> Result<InboundMessageDetails, ClientError> {
+        let message_id = path_segment(message_id)?;
+        let request = self
+            .http_client
+            .get(self.endpoint(&format!("/messages/inbound/{message_id}/details"))?);
+
+        self.execute(request).await
+    }
+
+    /// Processes a message that was blocked by the inbound spam filter.
+    #[tracing::instrument(name = "Bypass inbound message using postmark client", skip(self))]
+    pub async fn bypass_inbound_message(
+        &self,
+        message_id: &str,
+    ) -> Result<ApiResponse, ClientError> {
+        let message_id = path_segment(message_id)?;
+        let request = self
+            .http_client
+            .put(self.endpoint(&format!("/messages/inbound/{message_id}/bypass"))?);
+
+        self.execute(request).await
+    }
+
+    /// Posts a message whose inbound webhook failed to the webhook again.
+    #[tracing::instrument(name = "Retry inbound message using postmark client", skip(self))]
+    pub async fn retry_inbound_message(
+        &self,
+        message_id: &str,
+    ) -> Result<ApiResponse, ClientError> {
+        let message_id = path_segment(message_id)?;
+        let request = self
+            .http_client
+            .put(self.endpoint(&format!("/messages/inbound/{message_id}/retry"))?);
+
+        self.execute(request).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_inbound_message_details_deserialization() {
+        let details: InboundMessageDetails = serde_json::from_value(json!({
+            "From": "john@example.com",
+            "FromName": "John Smith",
+            "FromFull": { "Email": "john@example.com", "Name": "John Smith", "MailboxHash": "" },
+            "To": "support+ticket-42@inbound.example.com",
+            "ToFull": [{
+                "Email": "support+ticket-42@inbound.example.com",
+                "Name": "",
+                "MailboxHash": "ticket-42"
+            }],
+            "CcFull": [],
+            "ReplyTo": "",
+            "OriginalRecipient": "support+ticket-42@inbound.example.com",
+            "Subject": "Re: Your ticket",
+            "Date": "Thu, 5 Apr 2012 16:59:01 +0200",
+            "MailboxHash": "ticket-42",
+            "TextBody": "Thanks!",
+            "HtmlBody": "<p>Thanks!</p>",
+            "Tag": "",
+            "Headers": [{ "Name": "X-Spam-Status", "Value": "No" }],
+            "Attachments": [{
+                "Name": "notes.txt",
+                "Content": "aGVsbG8=",
+                "ContentType": "text/plain",
+                "ContentLength": 5
+            }],
+            "MessageID": "792d4d72-a29a-4bfe-a2c5-f6e9fb3e62d6",
+            "BlockedReason": null,
+            "Status": "Failed"
+        }))
+        .unwrap();
+
+        assert_eq!(details.message.status, InboundMessageStatus::Failed);
+        assert_eq!(
+            details.message.to_full[0].mailbox_hash.as_deref(),
+            Some("ticket-42")
+        );
+        assert_eq!(details.header("x-spam-status"), Some("No"));
+        let attachment = &details.message.attachments[0];
+        assert_eq!(attachment.name(), "notes.txt");
+        assert_eq!(attachment.content_length(), Some(5));
+        assert_eq!(attachment.content().unwrap(), b"hello");
+    }
+
+    #[test]
+    fn test_unknown_status_is_read_but_never_sent() {
+        let status: InboundMessageStatus = serde_json::from_value(json!("deferred")).unwrap();
+        assert_eq!(status, InboundMessageStatus::Other);
+
+        let filter = InboundMessageFilter::new().status(status);
+        assert!(serde_json::to_value(&filter).is_err());
+    }
+
+    #[test]
+    fn test_listed_attachment_without_content() {
+        let attachment: Attachment = serde_json::from_value(json!({
+            "Name": "notes.txt",
+            "ContentType": "text/plain",
+            "ContentLength": 5
+        }))
+        .unwrap();
+
+        assert!(matches!(
+            attachment.content(),
+            Err(ClientError::InvalidAttachment(_))
+        ));
+    }
+}
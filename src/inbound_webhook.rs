@@ -0,0 +1,183 @@
+use crate::error::{ClientError, ParseError};
+use crate::{Attachment, Email, MessageHeader, NamedAddress};
+use serde::{Deserialize, Serialize};
+
+/// The JSON payload Postmark posts to an inbound webhook.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct InboundWebhook {
+    #[serde(rename = "MessageID")]
+    pub message_id: String,
+    pub message_stream: Option<String>,
+    pub from: String,
+    pub from_name: Option<String>,
+    pub from_full: NamedAddress,
+    pub to: Option<String>,
+    #[serde(default)]
+    pub to_full: Vec<NamedAddress>,
+    pub cc: Option<String>,
+    #[serde(default)]
+    pub cc_full: Vec<NamedAddress>,
+    pub bcc: Option<String>,
+    #[serde(default)]
+    pub bcc_full: Vec<NamedAddress>,
+    pub original_recipient: Option<String>,
+    pub reply_to: Option<String>,
+    pub subject: Option<String>,
+    pub date: String,
+    /// The part of the inbound address after the `+`, e.g. `ticket-42` for
+    /// `support+ticket-42@inbound.example.com`.
+    pub mailbox_hash: Option<String>,
+    pub text_body: Option<String>,
+    pub html_body: Option<String>,
+    /// The text body with the quoted reply removed.
+    pub stripped_text_reply: Option<String>,
+    pub tag: Option<String>,
+    #[serde(default)]
+    pub headers: Vec<MessageHeader>,
+    #[serde(default)]
+    pub attachments: Vec<Attachment>,
+}
+
+/// The spam verdict SpamAssassin added to the headers of an inbound message.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SpamHeaders {
+    /// Whether `X-Spam-Status` flagged the message.
+    pub is_spam: Option<bool>,
+    pub score: Option<f64>,
+    pub tests: Vec<String>,
+}
+
+impl InboundWebhook {
+    pub fn parse(body: &[u8]) -> Result<Self, ClientError> {
+        serde_json::from_slice(body).map_err(ClientError::Serde)
+    }
+
+    /// The validated address of the sender.
+    pub fn sender(&self) -> Result<Email, ParseError> {
+        self.from_full.to_email()
+    }
+
+    /// The validated `Reply-To` address, if the sender set one.
+    pub fn reply_to_email(&self) -> Result<Option<Email>, ParseError> {
+        match self.reply_to.as_deref().map(str::trim) {
+            Some(reply_to) if !reply_to.is_empty() => Email::parse(reply_to).map(Some),
+            _ => Ok(None),
+        }
+    }
+
+    /// The value of the first header called `name`, ignoring case.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|header| header.name.eq_ignore_ascii_case(name))
+            .map(|header| header.value.as_str())
+    }
+
+    pub fn spam_headers(&self) -> SpamHeaders {
+        SpamHeaders {
+            is_spam: self.header("X-Spam-Status").map(|status| {
+                status
+                    .trim_start()
+                    .get(..3)
+                    .is_some_and(|verdict| verdict.eq_ignore_ascii_case("yes"))
+            }),
+            score: self
+                .header("X-Spam-Score")
+                .and_then(|score| score.trim().parse().ok()),
+            tests: self
+                .header("X-Spam-Tests")
+                .map(|tests| {
+                    tests
+                        .split(',')
+                        .map(str::trim)
+                        .filter(|test| !test.is_empty())
+                        .map(str::to_string)
+                        .collect()
+                })
+                .unwrap_or_default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAYLOAD: &str = r#"{
+        "FromName": "Postmarkapp Support",
+        "MessageStream": "inbound",
+        "From": "support@postmarkapp.com",
+        "FromFull": { "Email": "Support@Postmarkapp.com", "Name": "Postmarkapp Support", "MailboxHash": "" },
+        "To": "\"Firstname Lastname\" <yourhash+SampleHash@inbound.postmarkapp.com>",
+        "ToFull": [{
+            "Email": "yourhash+SampleHash@inbound.postmarkapp.com",
+            "Name": "Firstname Lastname",
+            "MailboxHash": "SampleHash"
+        }],
+        "Cc": "\"First Cc\" <firstcc@postmarkapp.com>",
+        "CcFull": [{ "Email": "firstcc@postmarkapp.com", "Name": "First Cc", "MailboxHash": "" }],
+        "Bcc": "",
+        "BccFull": [],
+        "OriginalRecipient": "yourhash+SampleHash@inbound.postmarkapp.com",
+        "Subject": "Test subject",
+        "MessageID": "73e6d360-66eb-11e1-8e72-a8904824019b",
+        "ReplyTo": "replyto@postmarkapp.com",
+        "MailboxHash": "SampleHash",
+        "Date": "Fri, 1 Aug 2014 16:45:32 -04:00",
+        "TextBody": "This is a test text body.",
+        "HtmlBody": "<html><body><p>This is a test html body.</p></body></html>",
+        "StrippedTextReply": "This is the reply text",
+        "Tag": "TestTag",
+        "Headers": [
+            { "Name": "X-Spam-Checker-Version", "Value": "SpamAssassin 3.3.1 (2010-03-16) onrs-ord-pm-inbound1.wildbit.com" },
+            { "Name": "X-Spam-Status", "Value": "No" },
+            { "Name": "X-Spam-Score", "Value": "-0.1" },
+            { "Name": "X-Spam-Tests", "Value": "DKIM_SIGNED,DKIM_VALID,DKIM_VALID_AU,SPF_PASS" }
+        ],
+        "Attachments": [{
+            "Name": "test.txt",
+            "Content": "VGhpcyBpcyBhdHRhY2htZW50IGNvbnRlbnRzLCBiYXNlLTY0IGVuY29kZWQu",
+            "ContentType": "text/plain",
+            "ContentLength": 45
+        }]
+    }"#;
+
+    #[test]
+    fn test_inbound_webhook_parsing() {
+        let webhook = InboundWebhook::parse(PAYLOAD.as_bytes()).unwrap();
+
+        assert_eq!(webhook.mailbox_hash.as_deref(), Some("SampleHash"));
+        assert_eq!(
+            webhook.stripped_text_reply.as_deref(),
+            Some("This is the reply text")
+        );
+        assert_eq!(
+            webhook.to_full[0].name.as_deref(),
+            Some("Firstname Lastname")
+        );
+        assert_eq!(
+            webhook.sender().unwrap().as_ref(),
+            "support@postmarkapp.com"
+        );
+        assert_eq!(
+            webhook.reply_to_email().unwrap().unwrap().as_ref(),
+            "replyto@postmarkapp.com"
+        );
+        assert_eq!(
+            webhook.attachments[0].content().unwrap(),
+            b"This is attachment contents, base-64 encoded."
+        );
+    }
+
+    #[test]
+    fn test_spam_headers() {
+        let webhook = InboundWebhook::parse(PAYLOAD.as_bytes()).unwrap();
+
+        let spam = webhook.spam_headers();
+
+        assert_eq!(spam.is_spam, Some(false));
+        assert_eq!(spam.score, Some(-0.1));
+        assert_eq!(spam.tests.len(), 4);
+    }
+}
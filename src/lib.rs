@@ -1,25 +1,91 @@
 mod attachment;
 
 mod email;
-pub use email::Email;
+pub use email::{Email, NamedAddress};
 
 mod outbound_email_body;
 pub use outbound_email_body::*;
 
+mod templated_email_body;
+pub use templated_email_body::*;
+
+mod templates;
+pub use templates::*;
+
+mod template_validation;
+pub use template_validation::*;
+
+mod template_sync;
+pub use template_sync::*;
+
+pub mod mustachio;
+pub use mustachio::{MustachioTemplate, RenderedTemplate};
+
+mod bounces;
+pub use bounces::*;
+
+mod outbound_messages;
+pub use outbound_messages::*;
+
+mod stats;
+pub use stats::*;
+
+mod inbound_messages;
+pub use inbound_messages::*;
+
+mod inbound_webhook;
+pub use inbound_webhook::*;
+
+mod message_streams;
+pub use message_streams::*;
+
+mod server;
+pub use server::*;
+
+mod account_client;
+pub use account_client::*;
+
+mod suppressions;
+pub use suppressions::*;
+
+mod domains;
+pub use domains::*;
+
+mod sender_signatures;
+pub use sender_signatures::*;
+
+pub mod webhooks;
+
+mod webhook_config;
+pub use webhook_config::*;
+
+mod webhook_verifier;
+pub use webhook_verifier::*;
+
+#[cfg(feature = "axum")]
+mod axum_webhooks;
+#[cfg(feature = "axum")]
+pub use axum_webhooks::*;
+
 pub mod error;
 use crate::error::ClientError;
 
+use std::future::Future;
+use std::ops::ControlFlow;
 use std::time::Duration;
 
 pub use crate::attachment::Attachment;
 pub use reqwest::Url;
 pub use secrecy::{ExposeSecret, SecretString};
 
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
 const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
 const MAX_BATCH_SIZE: usize = 500;
+const SERVER_TOKEN_HEADER: &str = "X-Postmark-Server-Token";
+const ACCOUNT_TOKEN_HEADER: &str = "X-Postmark-Account-Token";
 
 #[derive(Clone, Debug)]
 pub struct Client {
@@ -76,10 +142,7 @@ impl ClientBuilder {
 
         let timeout = self.timeout.unwrap_or(DEFAULT_TIMEOUT);
 
-        let http_client = reqwest::Client::builder()
-            .timeout(timeout)
-            .build()
-            .map_err(ClientError::Reqwest)?;
+        let http_client = build_http_client(timeout)?;
 
         Ok(Client {
             http_client,
@@ -104,48 +167,10 @@ impl Client {
         &self,
         body: &OutboundEmailBody,
     ) -> Result<SendEmailResponse, ClientError> {
-        let url = self
-            .base_url
-            .join("/email")
-            .map_err(|e| ClientError::Configuration(format!("Postmark invalid URL: {}", e)))?;
-
         let body: SendEmailRequest = (body, &self.sender).into();
+        let request = self.http_client.post(self.endpoint("/email")?).json(&body);
 
-        let resp = self
-            .http_client
-            .post(url)
-            .header("X-Postmark-Server-Token", self.auth_token.expose_secret())
-            .json(&body)
-            .send()
-            .await
-            .map_err(|err| {
-                tracing::error!("Postmark: failed to send email: {}", err);
-                if err.is_timeout() {
-                    ClientError::Timeout(self.timeout.as_secs())
-                } else {
-                    ClientError::Reqwest(err)
-                }
-            })?;
-
-        let status_code = resp.status();
-        let message = resp.text().await.map_err(|err| {
-            tracing::error!("Postmark: failed to read response body: {}", err);
-            ClientError::Reqwest(err)
-        })?;
-
-        if status_code.is_success() {
-            serde_json::from_str(&message).map_err(|err| {
-                tracing::error!("Postmark: failed to parse response: {}", err);
-                ClientError::Serde(err)
-            })
-        } else if status_code.as_str() == "401" {
-            Err(ClientError::Authentication(message))
-        } else {
-            Err(ClientError::ServerResponse {
-                status_code,
-                message,
-            })
-        }
+        self.execute(request).await
     }
 
     #[tracing::instrument(
@@ -166,50 +191,197 @@ impl Client {
             )));
         }
 
-        let url = self
-            .base_url
-            .join("/email/batch")
-            .map_err(|e| ClientError::Configuration(format!("Invalid batch URL: {}", e)))?;
-
         let body: Vec<SendEmailRequest> = bodies
             .iter()
             .map(|body| (body, &self.sender).into())
             .collect();
+        let request = self
+            .http_client
+            .post(self.endpoint("/email/batch")?)
+            .json(&body);
+
+        self.execute(request).await
+    }
+
+    #[tracing::instrument(
+        name = "Send templated email using postmark client",
+        skip(self, body)
+    )]
+    pub async fn send_with_template<M: Serialize>(
+        &self,
+        body: &TemplatedEmailBody<M>,
+    ) -> Result<SendEmailResponse, ClientError> {
+        let body: SendTemplatedEmailRequest<M> = (body, &self.sender).into();
+        let request = self
+            .http_client
+            .post(self.endpoint("/email/withTemplate")?)
+            .json(&body);
+
+        self.execute(request).await
+    }
+
+    #[tracing::instrument(
+        name = "Send batch templated emails using postmark client",
+        skip(self, bodies)
+    )]
+    pub async fn send_batch_with_templates<M: Serialize>(
+        &self,
+        bodies: &[TemplatedEmailBody<M>],
+    ) -> Result<Vec<SendEmailResponse>, ClientError> {
+        if bodies.is_empty() {
+            return Ok(vec![]);
+        }
 
-        let resp = self
+        if bodies.len() > MAX_BATCH_SIZE {
+            return Err(ClientError::Configuration(format!(
+                "Batch size exceeds maximum allowed ({MAX_BATCH_SIZE})"
+            )));
+        }
+
+        let body = SendBatchTemplatedEmailRequest {
+            messages: bodies
+                .iter()
+                .map(|body| (body, &self.sender).into())
+                .collect(),
+        };
+        let request = self
             .http_client
-            .post(url.clone())
-            .header("X-Postmark-Server-Token", self.auth_token.expose_secret())
-            .json(&body)
-            .send()
-            .await
-            .map_err(|e| {
-                tracing::error!("Failed to send batch email: {}", e);
-                ClientError::Reqwest(e)
-            })?;
-
-        let status_code = resp.status();
-        let message = resp.text().await.map_err(|err| {
-            tracing::error!("Postmark: failed to read batch response body: {}", err);
-            ClientError::Reqwest(err)
+            .post(self.endpoint("/email/batchWithTemplates")?)
+            .json(&body);
+
+        self.execute(request).await
+    }
+
+    fn endpoint(&self, path: &str) -> Result<Url, ClientError> {
+        endpoint(&self.base_url, path)
+    }
+
+    /// Authenticates the request with the server token, sends it and maps
+    /// the Postmark response onto either `T` or a [`ClientError`].
+    async fn execute<T: DeserializeOwned>(
+        &self,
+        request: reqwest::RequestBuilder,
+    ) -> Result<T, ClientError> {
+        execute(request, SERVER_TOKEN_HEADER, &self.auth_token, self.timeout).await
+    }
+}
+
+fn build_http_client(timeout: Duration) -> Result<reqwest::Client, ClientError> {
+    reqwest::Client::builder()
+        .timeout(timeout)
+        .build()
+        .map_err(ClientError::Reqwest)
+}
+
+fn endpoint(base_url: &Url, path: &str) -> Result<Url, ClientError> {
+    base_url
+        .join(path)
+        .map_err(|e| ClientError::Configuration(format!("Postmark invalid URL: {}", e)))
+}
+
+/// Percent-encodes `segment`, such as a template alias or a message id, so
+/// it stays a single segment of an endpoint path.
+fn path_segment(segment: &str) -> Result<String, ClientError> {
+    // Dot segments are resolved away even when encoded.
+    if segment.is_empty() || segment == "." || segment == ".." {
+        return Err(ClientError::Configuration(format!(
+            "'{segment}' is not a valid path segment"
+        )));
+    }
+
+    let mut url = Url::parse("http://localhost/").expect("a valid URL");
+    url.path_segments_mut()
+        .expect("a URL with a path")
+        .pop()
+        .push(segment);
+
+    Ok(url.path()[1..].to_string())
+}
+
+/// Sends a request authenticated with `token` in the `header` header, shared
+/// by [`Client`] and [`AccountClient`].
+async fn execute<T: DeserializeOwned>(
+    request: reqwest::RequestBuilder,
+    header: &str,
+    token: &SecretString,
+    timeout: Duration,
+) -> Result<T, ClientError> {
+    let resp = request
+        .header("Accept", "application/json")
+        .header(header, token.expose_secret())
+        .send()
+        .await
+        .map_err(|err| {
+            tracing::error!("Postmark: failed to send request: {}", err);
+            if err.is_timeout() {
+                ClientError::Timeout(timeout.as_secs())
+            } else {
+                ClientError::Reqwest(err)
+            }
         })?;
 
-        if status_code.is_success() {
-            serde_json::from_str(&message).map_err(|err| {
-                tracing::error!("Postmark: failed to parse batch response: {}", err);
-                ClientError::Serde(err)
-            })
-        } else if status_code.as_str() == "401" {
-            Err(ClientError::Authentication(message))
-        } else {
-            Err(ClientError::ServerResponse {
-                status_code,
-                message,
-            })
+    let status_code = resp.status();
+    let message = resp.text().await.map_err(|err| {
+        tracing::error!("Postmark: failed to read response body: {}", err);
+        ClientError::Reqwest(err)
+    })?;
+
+    if status_code.is_success() {
+        serde_json::from_str(&message).map_err(|err| {
+            tracing::error!("Postmark: failed to parse response: {}", err);
+            ClientError::Serde(err)
+        })
+    } else if status_code.as_str() == "401" {
+        Err(ClientError::Authentication(message))
+    } else {
+        Err(ClientError::ServerResponse {
+            status_code,
+            message,
+        })
+    }
+}
+
+/// Walks a paged listing one page at a time. `fetch_page` is called with
+/// the offset to fetch from, and returns the items of that page along with
+/// the total count of the listing. `visit` sees each page as it arrives and
+/// can break to stop fetching.
+async fn visit_pages<T, F, Fut>(
+    mut fetch_page: F,
+    mut visit: impl FnMut(Vec<T>) -> ControlFlow<()>,
+) -> Result<(), ClientError>
+where
+    F: FnMut(u32) -> Fut,
+    Fut: Future<Output = Result<(Vec<T>, u32), ClientError>>,
+{
+    let mut offset = 0;
+
+    loop {
+        let (page, total_count) = fetch_page(offset).await?;
+        let fetched = page.len() as u32;
+        offset += fetched;
+
+        if visit(page).is_break() || fetched == 0 || offset >= total_count {
+            return Ok(());
         }
     }
 }
 
+/// Collects every item of a paged listing, see [`visit_pages`].
+async fn fetch_all_pages<T, F, Fut>(fetch_page: F) -> Result<Vec<T>, ClientError>
+where
+    F: FnMut(u32) -> Fut,
+    Fut: Future<Output = Result<(Vec<T>, u32), ClientError>>,
+{
+    let mut items = Vec::new();
+    visit_pages(fetch_page, |page| {
+        items.extend(page);
+        ControlFlow::Continue(())
+    })
+    .await?;
+
+    Ok(items)
+}
+
 #[derive(Serialize)]
 #[serde(rename_all = "PascalCase")]
 struct SendEmailRequest<'a> {
@@ -224,6 +396,8 @@ struct SendEmailRequest<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     tag: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    message_stream: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     html_body: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
     text_body: Option<&'a str>,
@@ -252,6 +426,7 @@ impl<'a> From<(&'a OutboundEmailBody, &'a Email)> for SendEmailRequest<'a> {
                 .map(|emails| emails.iter().map(|email| email.as_ref()).collect()),
             subject: request.subject.as_deref(),
             tag: request.tag.as_deref(),
+            message_stream: request.message_stream.as_deref(),
             html_body: request.html_body.as_deref(),
             text_body: request.text_body.as_deref(),
             reply_to: request.reply_to.as_ref().map(|reply_to| reply_to.as_ref()),
@@ -263,6 +438,66 @@ impl<'a> From<(&'a OutboundEmailBody, &'a Email)> for SendEmailRequest<'a> {
     }
 }
 
+#[derive(Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct SendTemplatedEmailRequest<'a, M> {
+    #[serde(flatten)]
+    template: &'a TemplateRef,
+    template_model: &'a M,
+    inline_css: bool,
+    from: &'a str,
+    to: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cc: Option<Vec<&'a str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    bcc: Option<Vec<&'a str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tag: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    message_stream: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reply_to: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    metadata: Option<Value>,
+    track_opens: bool,
+    track_links: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    attachments: Option<Vec<Attachment>>,
+}
+
+impl<'a, M> From<(&'a TemplatedEmailBody<M>, &'a Email)> for SendTemplatedEmailRequest<'a, M> {
+    fn from((request, from): (&'a TemplatedEmailBody<M>, &'a Email)) -> Self {
+        SendTemplatedEmailRequest {
+            template: &request.template,
+            template_model: &request.template_model,
+            inline_css: request.inline_css,
+            from: from.as_ref(),
+            to: request.to.as_ref(),
+            cc: request
+                .cc
+                .as_ref()
+                .map(|emails| emails.iter().map(|email| email.as_ref()).collect()),
+            bcc: request
+                .bcc
+                .as_ref()
+                .map(|emails| emails.iter().map(|email| email.as_ref()).collect()),
+            tag: request.tag.as_deref(),
+            message_stream: request.message_stream.as_deref(),
+            reply_to: request.reply_to.as_ref().map(|reply_to| reply_to.as_ref()),
+            metadata: request.metadata.clone(),
+            track_opens: request.track_opens,
+            track_links: request.track_links.as_str(),
+            attachments: request.attachments.clone(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct SendBatchTemplatedEmailRequest<'a, M> {
+    messages: Vec<SendTemplatedEmailRequest<'a, M>>,
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, Default, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct SendEmailResponse {
@@ -274,9 +509,19 @@ pub struct SendEmailResponse {
     to: String,
 }
 
+/// The generic `{ ErrorCode, Message }` body Postmark returns from endpoints
+/// that have no resource to hand back, e.g. deletions.
+#[derive(Clone, Debug, Eq, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ApiResponse {
+    pub error_code: i64,
+    pub message: String,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use serde_json::json;
 
     #[test]
     fn test_internal_request_conversion() {
@@ -301,5 +546,44 @@ mod tests {
         assert_eq!(internal.text_body, Some("Text Content"));
         assert!(internal.track_opens);
         assert_eq!(internal.track_links, "HtmlAndText");
+        assert_eq!(internal.message_stream, None);
+    }
+
+    #[test]
+    fn test_internal_request_serializes_message_stream() {
+        let to = Email::parse("recipient@example.com").unwrap();
+        let request = OutboundEmailBody::builder(to)
+            .subject("Our newsletter")
+            .text_body("News")
+            .message_stream("broadcasts")
+            .build();
+
+        let from = Email::parse("from@example.com").unwrap();
+        let internal: SendEmailRequest = (&request, &from).into();
+        let value = serde_json::to_value(&internal).unwrap();
+
+        assert_eq!(value["MessageStream"], "broadcasts");
+    }
+
+    #[test]
+    fn test_internal_templated_request_serialization() {
+        let to = Email::parse("recipient@example.com").unwrap();
+        let request = TemplatedEmailBody::builder(to, "welcome", json!({ "name": "Jane" }))
+            .tag("onboarding")
+            .message_stream("outbound")
+            .build();
+
+        let from = Email::parse("from@example.com").unwrap();
+        let internal: SendTemplatedEmailRequest<Value> = (&request, &from).into();
+        let value = serde_json::to_value(&internal).unwrap();
+
+        assert_eq!(value["TemplateAlias"], "welcome");
+        assert!(value.get("TemplateId").is_none());
+        assert_eq!(value["TemplateModel"]["name"], "Jane");
+        assert_eq!(value["From"], "from@example.com");
+        assert_eq!(value["To"], "recipient@example.com");
+        assert_eq!(value["Tag"], "onboarding");
+        assert_eq!(value["InlineCss"], true);
+        assert_eq!(value["MessageStream"], "outbound");
     }
 }
@@ -0,0 +1,318 @@
+use crate::error::ClientError;
+use crate::{path_segment, Client};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum MessageStreamType {
+    Transactional,
+    Broadcasts,
+    Inbound,
+    /// A stream type this client does not know about yet. It can't be sent
+    /// back to Postmark.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+/// How unsubscribes from a broadcast stream are handled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum UnsubscribeHandlingType {
+    None,
+    Postmark,
+    Custom,
+    /// A handling type this client does not know about yet. It can't be sent
+    /// back to Postmark.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SubscriptionManagementConfiguration {
+    pub unsubscribe_handling_type: UnsubscribeHandlingType,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct MessageStream {
+    #[serde(rename = "ID")]
+    pub id: String,
+    #[serde(rename = "ServerID")]
+    pub server_id: i64,
+    pub name: String,
+    pub description: Option<String>,
+    pub message_stream_type: MessageStreamType,
+    pub created_at: String,
+    pub updated_at: Option<String>,
+    pub archived_at: Option<String>,
+    /// When an archived stream and its data will be deleted.
+    pub expected_purge_date: Option<String>,
+    pub subscription_management_configuration: Option<SubscriptionManagementConfiguration>,
+}
+
+impl MessageStream {
+    pub fn is_archived(&self) -> bool {
+        self.archived_at.is_some()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct MessageStreamList {
+    pub total_count: u32,
+    pub message_streams: Vec<MessageStream>,
+}
+
+/// The response to archiving a stream. The stream can be unarchived until
+/// `expected_purge_date`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct MessageStreamArchive {
+    #[serde(rename = "ID")]
+    pub id: String,
+    #[serde(rename = "ServerID")]
+    pub server_id: i64,
+    pub expected_purge_date: String,
+}
+
+/// Query parameters for `GET /message-streams`.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct MessageStreamFilter {
+    #[serde(rename = "MessageStreamType", skip_serializing_if = "Option::is_none")]
+    message_stream_type: Option<MessageStreamType>,
+    #[serde(rename = "IncludeArchivedStreams")]
+    include_archived_streams: bool,
+}
+
+impl MessageStreamFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn message_stream_type(mut self, message_stream_type: MessageStreamType) -> Self {
+        self.message_stream_type = Some(message_stream_type);
+        self
+    }
+
+    pub fn include_archived_streams(mut self, include_archived_streams: bool) -> Self {
+        self.include_archived_streams = include_archived_streams;
+        self
+    }
+}
+
+/// The fields sent when creating or editing a message stream. The id and
+/// type can only be set on creation; fields left unset are omitted.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct MessageStreamBody {
+    #[serde(rename = "ID", skip_serializing_if = "Option::is_none")]
+    pub(crate) id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) message_stream_type: Option<MessageStreamType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) subscription_management_configuration: Option<SubscriptionManagementConfiguration>,
+}
+
+impl MessageStreamBody {
+    pub fn builder() -> MessageStreamBodyBuilder {
+        MessageStreamBodyBuilder::new()
+    }
+}
+
+// The builder for MessageStreamBody
+#[derive(Debug, Clone, Default)]
+pub struct MessageStreamBodyBuilder {
+    body: MessageStreamBody,
+}
+
+impl MessageStreamBodyBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn id(mut self, id: impl Into<String>) -> Self {
+        self.body.id = Some(id.into());
+        self
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.body.name = Some(name.into());
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.body.description = Some(description.into());
+        self
+    }
+
+    pub fn message_stream_type(mut self, message_stream_type: MessageStreamType) -> Self {
+        self.body.message_stream_type = Some(message_stream_type);
+        self
+    }
+
+    pub fn unsubscribe_handling_type(mut self, handling: UnsubscribeHandlingType) -> Self {
+        self.body.subscription_management_configuration =
+            Some(SubscriptionManagementConfiguration {
+                unsubscribe_handling_type: handling,
+            });
+        self
+    }
+
+    pub fn build(self) -> MessageStreamBody {
+        self.body
+    }
+}
+
+impl Client {
+    #[tracing::instrument(name = "List message streams using postmark client", skip(self))]
+    pub async fn list_message_streams(
+        &self,
+        filter: &MessageStreamFilter,
+    ) -> Result<MessageStreamList, ClientError> {
+        let request = self
+            .http_client
+            .get(self.endpoint("/message-streams")?)
+            .query(filter);
+
+        self.execute(request).await
+    }
+
+    #[tracing::instrument(name = "Get message stream using postmark client", skip(self))]
+    pub async fn get_message_stream(&self, stream_id: &str) -> Result<MessageStream, ClientError> {
+        let stream_id = path_segment(stream_id)?;
+        let request = self
+            .http_client
+            .get(self.endpoint(&format!("/message-streams/{stream_id}"))?);
+
+        self.execute(request).await
+    }
+
+    #[tracing::instrument(name = "Create message stream using postmark client", skip(self, body))]
+    pub async fn create_message_stream(
+        &self,
+        body: &MessageStreamBody,
+    ) -> Result<MessageStream, ClientError> {
+        let request = self
+            .http_client
+            .post(self.endpoint("/message-streams")?)
+            .json(body);
+
+        self.execute(request).await
+    }
+
+    #[tracing::instrument(name = "Edit message stream using postmark client", skip(self, body))]
+    pub async fn edit_message_stream(
+        &self,
+        stream_id: &str,
+        body: &MessageStreamBody,
+    ) -> Result<MessageStream, ClientError> {
+        let stream_id = path_segment(stream_id)?;
+        let request = self
+            .http_client
+            .patch(self.endpoint(&format!("/message-streams/{stream_id}"))?)
+            .json(body);
+
+        self.execute(request).await
+    }
+
+    /// Archives a stream. Postmark deletes it and its data after the
+    /// returned purge date unless it is unarchived first.
+    #[tracing::instrument(name = "Archive message stream using postmark client", skip(self))]
+    pub async fn archive_message_stream(
+        &self,
+        stream_id: &str,
+    ) -> Result<MessageStreamArchive, ClientError> {
+        let stream_id = path_segment(stream_id)?;
+        let request = self
+            .http_client
+            .post(self.endpoint(&format!("/message-streams/{stream_id}/archive"))?);
+
+        self.execute(request).await
+    }
+
+    #[tracing::instrument(name = "Unarchive message stream using postmark client", skip(self))]
+    pub async fn unarchive_message_stream(
+        &self,
+        stream_id: &str,
+    ) -> Result<MessageStream, ClientError> {
+        let stream_id = path_segment(stream_id)?;
+        let request = self
+            .http_client
+            .post(self.endpoint(&format!("/message-streams/{stream_id}/unarchive"))?);
+
+        self.execute(request).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_message_stream_deserialization() {
+        let stream: MessageStream = serde_json::from_value(json!({
+            "ID": "broadcasts",
+            "ServerID": 123457,
+            "Name": "Broadcast Stream",
+            "Description": "This is my stream to send broadcast messages",
+            "MessageStreamType": "Broadcasts",
+            "CreatedAt": "2020-07-01T00:00:00-04:00",
+            "UpdatedAt": "2020-07-01T00:00:00-04:00",
+            "ArchivedAt": null,
+            "ExpectedPurgeDate": null,
+            "SubscriptionManagementConfiguration": {
+                "UnsubscribeHandlingType": "Postmark"
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(stream.message_stream_type, MessageStreamType::Broadcasts);
+        assert!(!stream.is_archived());
+        assert_eq!(
+            stream
+                .subscription_management_configuration
+                .unwrap()
+                .unsubscribe_handling_type,
+            UnsubscribeHandlingType::Postmark
+        );
+    }
+
+    #[test]
+    fn test_message_stream_body_serialization() {
+        let body = MessageStreamBody::builder()
+            .id("newsletters")
+            .name("Newsletters")
+            .message_stream_type(MessageStreamType::Broadcasts)
+            .unsubscribe_handling_type(UnsubscribeHandlingType::Custom)
+            .build();
+
+        let value = serde_json::to_value(&body).unwrap();
+
+        assert_eq!(
+            value,
+            json!({
+                "ID": "newsletters",
+                "Name": "Newsletters",
+                "MessageStreamType": "Broadcasts",
+                "SubscriptionManagementConfiguration": { "UnsubscribeHandlingType": "Custom" }
+            })
+        );
+    }
+
+    #[test]
+    fn test_unknown_stream_types_are_read_but_never_sent() {
+        let stream_type: MessageStreamType = serde_json::from_value(json!("Archive")).unwrap();
+        let handling: UnsubscribeHandlingType = serde_json::from_value(json!("External")).unwrap();
+
+        assert_eq!(stream_type, MessageStreamType::Other);
+        assert_eq!(handling, UnsubscribeHandlingType::Other);
+        let body = MessageStreamBody::builder()
+            .message_stream_type(stream_type)
+            .build();
+        assert!(serde_json::to_value(&body).is_err());
+    }
+}
@@ -0,0 +1,549 @@
+//! An offline renderer for Postmark's Mustachio template syntax, used to
+//! preview templates and test their models without calling the API.
+
+use crate::error::ClientError;
+use crate::{Email, OutboundEmailBody, OutboundEmailBodyBuilder, Template, TemplatedEmailBody};
+use serde::Serialize;
+use serde_json::Value;
+
+/// The variable a layout uses to mark where the template content goes.
+const LAYOUT_CONTENT: &str = "@content";
+
+/// A parsed template in Postmark's Mustachio syntax.
+///
+/// Supported tags are `{{ path }}` (escaped when rendering HTML),
+/// `{{{ path }}}` and `{{& path }}` (never escaped), `{{#section}}`,
+/// `{{^inverted}}`, `{{#each list}}` and `{{! comments }}`. Paths are
+/// resolved against the current scope; `.` refers to the scope itself and
+/// each leading `../` moves one scope up.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MustachioTemplate {
+    nodes: Vec<Node>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Node {
+    Text(String),
+    Variable {
+        path: String,
+        escape: bool,
+    },
+    Section {
+        path: String,
+        inverted: bool,
+        children: Vec<Node>,
+    },
+    Each {
+        path: String,
+        children: Vec<Node>,
+    },
+}
+
+/// Whether variables rendered with `{{ }}` are HTML-escaped. Postmark only
+/// escapes the HTML body; the subject and text body are rendered verbatim.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Escape {
+    Html,
+    None,
+}
+
+impl MustachioTemplate {
+    pub fn parse(source: &str) -> Result<Self, ClientError> {
+        Parser { source, pos: 0 }.parse()
+    }
+
+    pub fn render(&self, model: &Value, escape: Escape) -> String {
+        self.render_with_content(model, escape, None)
+    }
+
+    /// Renders a layout, inserting `content` wherever it uses `{{{@content}}}`.
+    pub fn render_layout(&self, model: &Value, escape: Escape, content: &str) -> String {
+        self.render_with_content(model, escape, Some(content))
+    }
+
+    fn render_with_content(&self, model: &Value, escape: Escape, content: Option<&str>) -> String {
+        let mut out = String::new();
+        let mut scopes = vec![model];
+        let renderer = Renderer { escape, content };
+        renderer.render(&self.nodes, &mut scopes, &mut out);
+        out
+    }
+}
+
+struct Parser<'a> {
+    source: &'a str,
+    pos: usize,
+}
+
+enum Tag {
+    Variable(String, bool),
+    Open(String, bool),
+    Each(String),
+    Close(String),
+    Comment,
+}
+
+impl Parser<'_> {
+    fn parse(mut self) -> Result<MustachioTemplate, ClientError> {
+        let nodes = self.parse_nodes(None)?;
+        Ok(MustachioTemplate { nodes })
+    }
+
+    /// Parses nodes until the closing tag of `block`, or the end of input
+    /// when parsing the top level.
+    fn parse_nodes(&mut self, block: Option<(&str, usize)>) -> Result<Vec<Node>, ClientError> {
+        let mut nodes = Vec::new();
+
+        loop {
+            let rest = &self.source[self.pos..];
+            let Some(offset) = rest.find("{{") else {
+                if !rest.is_empty() {
+                    nodes.push(Node::Text(rest.to_string()));
+                }
+                self.pos = self.source.len();
+                return match block {
+                    Some((name, start)) => {
+                        Err(self.error(start, &format!("unclosed section '{name}'")))
+                    }
+                    None => Ok(nodes),
+                };
+            };
+
+            if offset > 0 {
+                nodes.push(Node::Text(rest[..offset].to_string()));
+            }
+
+            let start = self.pos + offset;
+            match self.parse_tag(start)? {
+                Tag::Variable(path, escape) => nodes.push(Node::Variable { path, escape }),
+                Tag::Comment => {}
+                Tag::Open(path, inverted) => {
+                    let children = self.parse_nodes(Some((&path, start)))?;
+                    nodes.push(Node::Section {
+                        path,
+                        inverted,
+                        children,
+                    });
+                }
+                Tag::Each(path) => {
+                    let children = self.parse_nodes(Some(("each", start)))?;
+                    nodes.push(Node::Each { path, children });
+                }
+                Tag::Close(name) => {
+                    return match block {
+                        Some((open, _)) if open == name => Ok(nodes),
+                        Some((open, _)) => Err(self.error(
+                            start,
+                            &format!("expected {{{{/{open}}}}} but found {{{{/{name}}}}}"),
+                        )),
+                        None => Err(self.error(start, &format!("unexpected {{{{/{name}}}}}"))),
+                    };
+                }
+            }
+        }
+    }
+
+    fn parse_tag(&mut self, start: usize) -> Result<Tag, ClientError> {
+        let (raw, open_len, close) = if self.source[start..].starts_with("{{{") {
+            (true, 3, "}}}")
+        } else {
+            (false, 2, "}}")
+        };
+
+        let inner_start = start + open_len;
+        let Some(len) = self.source[inner_start..].find(close) else {
+            return Err(self.error(start, "unclosed tag"));
+        };
+        self.pos = inner_start + len + close.len();
+
+        let inner = self.source[inner_start..inner_start + len].trim();
+        if raw {
+            return self
+                .path(start, inner)
+                .map(|path| Tag::Variable(path, false));
+        }
+
+        let tag = match inner.chars().next() {
+            Some('!') => Tag::Comment,
+            Some('&') => Tag::Variable(self.path(start, &inner[1..])?, false),
+            Some('^') => Tag::Open(self.path(start, &inner[1..])?, true),
+            Some('/') => Tag::Close(self.path(start, &inner[1..])?),
+            Some('#') => {
+                let inner = inner[1..].trim();
+                match inner.strip_prefix("each") {
+                    Some(path) if path.starts_with(char::is_whitespace) => {
+                        Tag::Each(self.path(start, path)?)
+                    }
+                    _ => Tag::Open(self.path(start, inner)?, false),
+                }
+            }
+            _ => Tag::Variable(self.path(start, inner)?, true),
+        };
+
+        Ok(tag)
+    }
+
+    fn path(&self, start: usize, path: &str) -> Result<String, ClientError> {
+        let path = path.trim();
+        if path.is_empty() || path.contains(char::is_whitespace) {
+            return Err(self.error(start, &format!("invalid tag name '{path}'")));
+        }
+        Ok(path.to_string())
+    }
+
+    fn error(&self, offset: usize, message: &str) -> ClientError {
+        let before = &self.source[..offset];
+        let line = before.matches('\n').count() + 1;
+        let column = before.len() - before.rfind('\n').map_or(0, |i| i + 1) + 1;
+        ClientError::TemplateRender(format!("{message} at line {line}, position {column}"))
+    }
+}
+
+struct Renderer<'c> {
+    escape: Escape,
+    content: Option<&'c str>,
+}
+
+impl Renderer<'_> {
+    fn render(&self, nodes: &[Node], scopes: &mut Vec<&Value>, out: &mut String) {
+        for node in nodes {
+            match node {
+                Node::Text(text) => out.push_str(text),
+                Node::Variable { path, escape } => {
+                    if path == LAYOUT_CONTENT {
+                        if let Some(content) = self.content {
+                            out.push_str(content);
+                        }
+                        continue;
+                    }
+                    if let Some(value) = resolve(path, scopes) {
+                        let text = display(value);
+                        if *escape && self.escape == Escape::Html {
+                            push_escaped(&text, out);
+                        } else {
+                            out.push_str(&text);
+                        }
+                    }
+                }
+                Node::Section {
+                    path,
+                    inverted,
+                    children,
+                } => {
+                    let value = resolve(path, scopes).filter(|value| is_truthy(value));
+                    match (value, inverted) {
+                        (None, true) => self.render(children, scopes, out),
+                        (Some(Value::Array(items)), false) => {
+                            self.render_each(items, children, scopes, out)
+                        }
+                        (Some(value), false) => {
+                            scopes.push(value);
+                            self.render(children, scopes, out);
+                            scopes.pop();
+                        }
+                        _ => {}
+                    }
+                }
+                Node::Each { path, children } => {
+                    if let Some(Value::Array(items)) = resolve(path, scopes) {
+                        self.render_each(items, children, scopes, out);
+                    }
+                }
+            }
+        }
+    }
+
+    fn render_each<'v>(
+        &self,
+        items: &'v [Value],
+        children: &[Node],
+        scopes: &mut Vec<&'v Value>,
+        out: &mut String,
+    ) {
+        for item in items {
+            scopes.push(item);
+            self.render(children, scopes, out);
+            scopes.pop();
+        }
+    }
+}
+
+fn resolve<'v>(path: &str, scopes: &[&'v Value]) -> Option<&'v Value> {
+    let mut depth = scopes.len().checked_sub(1)?;
+    let mut rest = path;
+    while let Some(stripped) = rest.strip_prefix("../") {
+        depth = depth.checked_sub(1)?;
+        rest = stripped;
+    }
+
+    let mut value = scopes[depth];
+    if rest == "." || rest == ".." {
+        return Some(value);
+    }
+    for segment in rest.split('.') {
+        value = value.get(segment)?;
+    }
+    Some(value)
+}
+
+fn is_truthy(value: &Value) -> bool {
+    match value {
+        Value::Null => false,
+        Value::Bool(value) => *value,
+        Value::Number(number) => number.as_f64() != Some(0.0),
+        Value::String(value) => !value.is_empty(),
+        Value::Array(items) => !items.is_empty(),
+        Value::Object(_) => true,
+    }
+}
+
+fn display(value: &Value) -> String {
+    match value {
+        Value::Null | Value::Array(_) | Value::Object(_) => String::new(),
+        Value::String(value) => value.clone(),
+        value => value.to_string(),
+    }
+}
+
+fn push_escaped(text: &str, out: &mut String) {
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            c => out.push(c),
+        }
+    }
+}
+
+/// The subject and bodies of a template rendered with a model.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RenderedTemplate {
+    pub subject: Option<String>,
+    pub html_body: Option<String>,
+    pub text_body: Option<String>,
+}
+
+impl RenderedTemplate {
+    /// Starts an [`OutboundEmailBody`] carrying the rendered content.
+    pub fn into_email_body(self, to: Email) -> OutboundEmailBodyBuilder {
+        let mut builder = OutboundEmailBody::builder(to);
+        if let Some(subject) = self.subject {
+            builder = builder.subject(subject);
+        }
+        if let Some(html_body) = self.html_body {
+            builder = builder.html_body(html_body);
+        }
+        if let Some(text_body) = self.text_body {
+            builder = builder.text_body(text_body);
+        }
+        builder
+    }
+}
+
+/// Renders the subject, HTML and text parts of a template, wrapping the
+/// bodies in `layout` when one is given.
+pub fn render_parts<M: Serialize>(
+    subject: Option<&str>,
+    html_body: Option<&str>,
+    text_body: Option<&str>,
+    layout: Option<&Template>,
+    model: &M,
+) -> Result<RenderedTemplate, ClientError> {
+    let model = serde_json::to_value(model)?;
+    let render = |source: Option<&str>, escape: Escape| -> Result<Option<String>, ClientError> {
+        source
+            .map(|source| Ok(MustachioTemplate::parse(source)?.render(&model, escape)))
+            .transpose()
+    };
+    let wrap = |content: Option<String>,
+                layout: Option<&str>,
+                escape: Escape|
+     -> Result<Option<String>, ClientError> {
+        match (content, layout) {
+            (Some(content), Some(layout)) => Ok(Some(
+                MustachioTemplate::parse(layout)?.render_layout(&model, escape, &content),
+            )),
+            (content, _) => Ok(content),
+        }
+    };
+
+    let html_body = render(html_body, Escape::Html)?;
+    let text_body = render(text_body, Escape::None)?;
+
+    Ok(RenderedTemplate {
+        subject: render(subject, Escape::None)?,
+        html_body: wrap(
+            html_body,
+            layout.and_then(|layout| layout.html_body.as_deref()),
+            Escape::Html,
+        )?,
+        text_body: wrap(
+            text_body,
+            layout.and_then(|layout| layout.text_body.as_deref()),
+            Escape::None,
+        )?,
+    })
+}
+
+impl Template {
+    /// Renders this template locally with `model`, the way Postmark would
+    /// when sending it. `layout` should be the template named by
+    /// `layout_template`, if any.
+    pub fn render<M: Serialize>(
+        &self,
+        model: &M,
+        layout: Option<&Template>,
+    ) -> Result<RenderedTemplate, ClientError> {
+        render_parts(
+            self.subject.as_deref(),
+            self.html_body.as_deref(),
+            self.text_body.as_deref(),
+            layout,
+            model,
+        )
+    }
+}
+
+impl<M: Serialize> TemplatedEmailBody<M> {
+    /// Renders `template` with this email's model into an [`OutboundEmailBody`]
+    /// that keeps the recipients, tag, message stream, metadata, tracking and
+    /// attachments.
+    pub fn render(
+        &self,
+        template: &Template,
+        layout: Option<&Template>,
+    ) -> Result<OutboundEmailBody, ClientError> {
+        let rendered = template.render(&self.template_model, layout)?;
+
+        let mut body = rendered.into_email_body(self.to.clone()).build();
+        body.cc = self.cc.clone();
+        body.bcc = self.bcc.clone();
+        body.tag = self.tag.clone();
+        body.message_stream = self.message_stream.clone();
+        body.reply_to = self.reply_to.clone();
+        body.metadata = self.metadata.clone();
+        body.track_opens = self.track_opens;
+        body.track_links = self.track_links;
+        body.attachments = self.attachments.clone();
+
+        Ok(body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TemplateType;
+    use serde_json::json;
+
+    fn render(source: &str, model: Value) -> String {
+        MustachioTemplate::parse(source)
+            .unwrap()
+            .render(&model, Escape::Html)
+    }
+
+    #[test]
+    fn test_variables_are_escaped_unless_raw() {
+        let model = json!({ "name": "<b>Jane</b>", "company": { "name": "A&B" } });
+
+        assert_eq!(
+            render("Hi {{ name }} from {{company.name}}", model.clone()),
+            "Hi &lt;b&gt;Jane&lt;/b&gt; from A&amp;B"
+        );
+        assert_eq!(
+            render("{{{name}}} {{& name}}", model),
+            "<b>Jane</b> <b>Jane</b>"
+        );
+    }
+
+    #[test]
+    fn test_text_is_not_escaped() {
+        let template = MustachioTemplate::parse("{{name}}").unwrap();
+
+        assert_eq!(
+            template.render(&json!({ "name": "a<b" }), Escape::None),
+            "a<b"
+        );
+    }
+
+    #[test]
+    fn test_sections_and_inverted_sections() {
+        let source = "{{#user}}Hi {{name}}{{/user}}{{^user}}Hi stranger{{/user}}";
+
+        assert_eq!(
+            render(source, json!({ "user": { "name": "Jane" } })),
+            "Hi Jane"
+        );
+        assert_eq!(render(source, json!({ "user": null })), "Hi stranger");
+        assert_eq!(render(source, json!({})), "Hi stranger");
+    }
+
+    #[test]
+    fn test_each_with_parent_scope() {
+        let source = "{{#each items}}{{.}} for {{../name}}; {{/each}}";
+        let model = json!({ "name": "Jane", "items": ["tea", "cake"] });
+
+        assert_eq!(render(source, model), "tea for Jane; cake for Jane; ");
+    }
+
+    #[test]
+    fn test_mismatched_close_tag_is_an_error() {
+        let outcome = MustachioTemplate::parse("line one\n{{#a}}{{/b}}");
+
+        match outcome {
+            Err(ClientError::TemplateRender(message)) => {
+                assert!(message.contains("line 2, position 7"), "{message}")
+            }
+            other => panic!("unexpected outcome: {other:?}"),
+        }
+        assert!(MustachioTemplate::parse("{{#each items}}").is_err());
+    }
+
+    #[test]
+    fn test_template_renders_into_layout() {
+        let layout = Template {
+            template_id: 1,
+            name: "Base".to_string(),
+            alias: Some("base".to_string()),
+            subject: None,
+            html_body: Some(
+                "<main>{{{ @content }}}</main><footer>{{company}}</footer>".to_string(),
+            ),
+            text_body: Some("{{{@content}}}\n--\n{{company}}".to_string()),
+            associated_server_id: 1,
+            active: true,
+            template_type: TemplateType::Layout,
+            layout_template: None,
+        };
+        let template = Template {
+            template_id: 2,
+            name: "Welcome".to_string(),
+            alias: Some("welcome".to_string()),
+            subject: Some("Welcome {{name}}".to_string()),
+            html_body: Some("<p>Hi {{name}}</p>".to_string()),
+            text_body: Some("Hi {{name}}".to_string()),
+            associated_server_id: 1,
+            active: true,
+            template_type: TemplateType::Standard,
+            layout_template: Some("base".to_string()),
+        };
+
+        let to = Email::parse("to@example.com").unwrap();
+        let email =
+            TemplatedEmailBody::builder(to, "welcome", json!({ "name": "J&J", "company": "Acme" }))
+                .tag("welcome")
+                .build();
+        let body = email.render(&template, Some(&layout)).unwrap();
+
+        assert_eq!(body.subject.as_deref(), Some("Welcome J&J"));
+        assert_eq!(
+            body.html_body.as_deref(),
+            Some("<main><p>Hi J&amp;J</p></main><footer>Acme</footer>")
+        );
+        assert_eq!(body.text_body.as_deref(), Some("Hi J&J\n--\nAcme"));
+        assert_eq!(body.tag.as_deref(), Some("welcome"));
+    }
+}
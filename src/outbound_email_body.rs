@@ -1,8 +1,9 @@
 use crate::attachment::Attachment;
 use crate::Email;
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum TrackLink {
     None,
     HtmlAndText,
@@ -28,6 +29,7 @@ pub struct OutboundEmailBody {
     pub(crate) cc: Option<Vec<Email>>,
     pub(crate) bcc: Option<Vec<Email>>,
     pub(crate) tag: Option<String>,
+    pub(crate) message_stream: Option<String>,
     pub(crate) html_body: Option<String>,
     pub(crate) text_body: Option<String>,
     pub(crate) reply_to: Option<Email>,
@@ -50,6 +52,7 @@ pub struct OutboundEmailBodyBuilder {
     cc: Option<Vec<Email>>,
     bcc: Option<Vec<Email>>,
     tag: Option<String>,
+    message_stream: Option<String>,
     html_body: Option<String>,
     text_body: Option<String>,
     reply_to: Option<Email>,
@@ -69,6 +72,7 @@ impl OutboundEmailBodyBuilder {
             cc: None,
             bcc: None,
             tag: None,
+            message_stream: None,
             reply_to: None,
             metadata: None,
             track_opens: true,
@@ -107,6 +111,13 @@ impl OutboundEmailBodyBuilder {
         self
     }
 
+    /// The message stream to send through. Postmark uses the default
+    /// transactional stream when none is set.
+    pub fn message_stream(mut self, message_stream: impl Into<String>) -> Self {
+        self.message_stream = Some(message_stream.into());
+        self
+    }
+
     pub fn reply_to(mut self, reply_to: Email) -> Self {
         self.reply_to = Some(reply_to);
         self
@@ -139,6 +150,7 @@ impl OutboundEmailBodyBuilder {
             cc: self.cc,
             bcc: self.bcc,
             tag: self.tag,
+            message_stream: self.message_stream,
             html_body: self.html_body,
             text_body: self.text_body,
             reply_to: self.reply_to,
@@ -0,0 +1,717 @@
+use crate::error::ClientError;
+use crate::{path_segment, Client, NamedAddress, TrackLink};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OutboundMessageStatus {
+    #[serde(rename = "queued", alias = "Queued")]
+    Queued,
+    #[serde(rename = "sent", alias = "Sent")]
+    Sent,
+    #[serde(rename = "processed", alias = "Processed")]
+    Processed,
+    /// A status this client does not know about yet. It can't be used as a
+    /// filter.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+/// An outbound message as returned by `GET /messages/outbound`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct OutboundMessage {
+    pub tag: Option<String>,
+    #[serde(rename = "MessageID")]
+    pub message_id: String,
+    pub message_stream: Option<String>,
+    #[serde(default)]
+    pub to: Vec<NamedAddress>,
+    #[serde(default)]
+    pub cc: Vec<NamedAddress>,
+    #[serde(default)]
+    pub bcc: Vec<NamedAddress>,
+    #[serde(default)]
+    pub recipients: Vec<String>,
+    pub received_at: String,
+    pub from: String,
+    pub subject: Option<String>,
+    /// The names of the attached files.
+    #[serde(default)]
+    pub attachments: Vec<String>,
+    pub status: OutboundMessageStatus,
+    pub track_opens: bool,
+    pub track_links: TrackLink,
+    #[serde(default)]
+    pub metadata: BTreeMap<String, String>,
+    #[serde(default)]
+    pub sandboxed: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct OutboundMessageList {
+    pub total_count: u32,
+    pub messages: Vec<OutboundMessage>,
+}
+
+/// An outbound message with its content and event timeline, as returned by
+/// `GET /messages/outbound/{messageid}/details`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct OutboundMessageDetails {
+    #[serde(flatten)]
+    pub message: OutboundMessage,
+    pub text_body: Option<String>,
+    pub html_body: Option<String>,
+    /// The raw MIME body of the message.
+    pub body: Option<String>,
+    #[serde(default)]
+    pub message_events: Vec<MessageEvent>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MessageEventType {
+    Delivered,
+    Opened,
+    Bounced,
+    Transient,
+    LinkClicked,
+    SubscriptionChanged,
+    /// An event type this client does not know about yet.
+    #[serde(other)]
+    Other,
+}
+
+/// One entry of a message's event timeline.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct MessageEvent {
+    pub recipient: String,
+    #[serde(rename = "Type")]
+    pub event_type: MessageEventType,
+    pub received_at: String,
+    #[serde(default)]
+    pub details: MessageEventDetails,
+}
+
+/// The details of a message event. Which fields are set depends on the
+/// event type.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct MessageEventDetails {
+    pub summary: Option<String>,
+    pub delivery_message: Option<String>,
+    pub destination_server: Option<String>,
+    #[serde(rename = "DestinationIP")]
+    pub destination_ip: Option<String>,
+    #[serde(rename = "BounceID")]
+    pub bounce_id: Option<String>,
+    pub original_link: Option<String>,
+    pub suppress_sending: Option<String>,
+    pub suppression_reason: Option<String>,
+    pub origin: Option<String>,
+}
+
+/// The raw source of a message.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct MessageDump {
+    #[serde(default)]
+    pub body: String,
+}
+
+/// The email client or operating system an open or click came from.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct UserAgentInfo {
+    pub name: Option<String>,
+    pub company: Option<String>,
+    pub family: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct GeoLocation {
+    #[serde(rename = "CountryISOCode")]
+    pub country_iso_code: Option<String>,
+    pub country: Option<String>,
+    #[serde(rename = "RegionISOCode")]
+    pub region_iso_code: Option<String>,
+    pub region: Option<String>,
+    pub city: Option<String>,
+    pub zip: Option<String>,
+    /// Latitude and longitude, e.g. `45.2517,19.8369`.
+    pub coords: Option<String>,
+    #[serde(rename = "IP")]
+    pub ip: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Platform {
+    Desktop,
+    Mobile,
+    WebMail,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ClickLocation {
+    #[serde(rename = "HTML")]
+    Html,
+    Text,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct OpenEvent {
+    #[serde(default)]
+    pub client: UserAgentInfo,
+    #[serde(rename = "OS", default)]
+    pub os: UserAgentInfo,
+    pub platform: Platform,
+    pub user_agent: Option<String>,
+    #[serde(default)]
+    pub geo: GeoLocation,
+    #[serde(rename = "MessageID")]
+    pub message_id: String,
+    pub message_stream: Option<String>,
+    pub received_at: String,
+    pub tag: Option<String>,
+    pub recipient: String,
+    /// Whether this is the first time the recipient opened the message.
+    pub first_open: bool,
+    pub read_seconds: Option<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ClickEvent {
+    pub click_location: ClickLocation,
+    #[serde(default)]
+    pub client: UserAgentInfo,
+    #[serde(rename = "OS", default)]
+    pub os: UserAgentInfo,
+    pub platform: Platform,
+    pub user_agent: Option<String>,
+    pub original_link: String,
+    #[serde(default)]
+    pub geo: GeoLocation,
+    #[serde(rename = "MessageID")]
+    pub message_id: String,
+    pub message_stream: Option<String>,
+    pub received_at: String,
+    pub tag: Option<String>,
+    pub recipient: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct OpenEventList {
+    pub total_count: u32,
+    pub opens: Vec<OpenEvent>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ClickEventList {
+    pub total_count: u32,
+    pub clicks: Vec<ClickEvent>,
+}
+
+/// Query parameters shared by `GET /messages/outbound/opens` and
+/// `GET /messages/outbound/clicks`.
+#[derive(Debug, Clone, Serialize)]
+pub struct TrackingEventFilter {
+    count: u32,
+    offset: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    recipient: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tag: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    client_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    client_company: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    client_family: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    os_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    os_family: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    os_company: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    platform: Option<Platform>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    country: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    region: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    city: Option<String>,
+    #[serde(rename = "messagestream", skip_serializing_if = "Option::is_none")]
+    message_stream: Option<String>,
+}
+
+impl Default for TrackingEventFilter {
+    fn default() -> Self {
+        Self {
+            count: 100,
+            offset: 0,
+            recipient: None,
+            tag: None,
+            client_name: None,
+            client_company: None,
+            client_family: None,
+            os_name: None,
+            os_family: None,
+            os_company: None,
+            platform: None,
+            country: None,
+            region: None,
+            city: None,
+            message_stream: None,
+        }
+    }
+}
+
+impl TrackingEventFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn count(mut self, count: u32) -> Self {
+        self.count = count;
+        self
+    }
+
+    pub fn offset(mut self, offset: u32) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    pub fn recipient(mut self, recipient: impl Into<String>) -> Self {
+        self.recipient = Some(recipient.into());
+        self
+    }
+
+    pub fn tag(mut self, tag: impl Into<String>) -> Self {
+        self.tag = Some(tag.into());
+        self
+    }
+
+    pub fn client_name(mut self, client_name: impl Into<String>) -> Self {
+        self.client_name = Some(client_name.into());
+        self
+    }
+
+    pub fn client_company(mut self, client_company: impl Into<String>) -> Self {
+        self.client_company = Some(client_company.into());
+        self
+    }
+
+    pub fn client_family(mut self, client_family: impl Into<String>) -> Self {
+        self.client_family = Some(client_family.into());
+        self
+    }
+
+    pub fn os_name(mut self, os_name: impl Into<String>) -> Self {
+        self.os_name = Some(os_name.into());
+        self
+    }
+
+    pub fn os_family(mut self, os_family: impl Into<String>) -> Self {
+        self.os_family = Some(os_family.into());
+        self
+    }
+
+    pub fn os_company(mut self, os_company: impl Into<String>) -> Self {
+        self.os_company = Some(os_company.into());
+        self
+    }
+
+    pub fn platform(mut self, platform: Platform) -> Self {
+        self.platform = Some(platform);
+        self
+    }
+
+    pub fn country(mut self, country: impl Into<String>) -> Self {
+        self.country = Some(country.into());
+        self
+    }
+
+    pub fn region(mut self, region: impl Into<String>) -> Self {
+        self.region = Some(region.into());
+        self
+    }
+
+    pub fn city(mut self, city: impl Into<String>) -> Self {
+        self.city = Some(city.into());
+        self
+    }
+
+    pub fn message_stream(mut self, message_stream: impl Into<String>) -> Self {
+        self.message_stream = Some(message_stream.into());
+        self
+    }
+}
+
+#[derive(Serialize)]
+struct PageQuery {
+    count: u32,
+    offset: u32,
+}
+
+/// Query parameters for `GET /messages/outbound`.
+#[derive(Debug, Clone, Serialize)]
+pub struct OutboundMessageFilter {
+    count: u32,
+    offset: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    recipient: Option<String>,
+    #[serde(rename = "fromemail", skip_serializing_if = "Option::is_none")]
+    from_email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tag: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    subject: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status: Option<OutboundMessageStatus>,
+    #[serde(rename = "fromdate", skip_serializing_if = "Option::is_none")]
+    from_date: Option<String>,
+    #[serde(rename = "todate", skip_serializing_if = "Option::is_none")]
+    to_date: Option<String>,
+    #[serde(rename = "messagestream", skip_serializing_if = "Option::is_none")]
+    message_stream: Option<String>,
+    #[serde(flatten)]
+    metadata: BTreeMap<String, String>,
+}
+
+impl Default for OutboundMessageFilter {
+    fn default() -> Self {
+        Self {
+            count: 100,
+            offset: 0,
+            recipient: None,
+            from_email: None,
+            tag: None,
+            subject: None,
+            status: None,
+            from_date: None,
+            to_date: None,
+            message_stream: None,
+            metadata: BTreeMap::new(),
+        }
+    }
+}
+
+impl OutboundMessageFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn count(mut self, count: u32) -> Self {
+        self.count = count;
+        self
+    }
+
+    pub fn offset(mut self, offset: u32) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    pub fn recipient(mut self, recipient: impl Into<String>) -> Self {
+        self.recipient = Some(recipient.into());
+        self
+    }
+
+    pub fn from_email(mut self, from_email: impl Into<String>) -> Self {
+        self.from_email = Some(from_email.into());
+        self
+    }
+
+    pub fn tag(mut self, tag: impl Into<String>) -> Self {
+        self.tag = Some(tag.into());
+        self
+    }
+
+    pub fn subject(mut self, subject: impl Into<String>) -> Self {
+        self.subject = Some(subject.into());
+        self
+    }
+
+    pub fn status(mut self, status: OutboundMessageStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Only messages from this date on, e.g. `2024-01-31`.
+    pub fn from_date(mut self, from_date: impl Into<String>) -> Self {
+        self.from_date = Some(from_date.into());
+        self
+    }
+
+    /// Only messages up to this date, e.g. `2024-02-29`.
+    pub fn to_date(mut self, to_date: impl Into<String>) -> Self {
+        self.to_date = Some(to_date.into());
+        self
+    }
+
+    pub fn message_stream(mut self, message_stream: impl Into<String>) -> Self {
+        self.message_stream = Some(message_stream.into());
+        self
+    }
+
+    /// Only messages whose metadata has `key` set to `value`. Can be
+    /// repeated to match several keys.
+    pub fn metadata(mut self, key: impl AsRef<str>, value: impl Into<String>) -> Self {
+        self.metadata
+            .insert(format!("metadata_{}", key.as_ref()), value.into());
+        self
+    }
+}
+
+impl Client {
+    #[tracing::instrument(name = "Search outbound messages using postmark client", skip(self))]
+    pub async fn search_outbound_messages(
+        &self,
+        filter: &OutboundMessageFilter,
+    ) -> Result<OutboundMessageList, ClientError> {
+        let request = self
+            .http_client
+            .get(self.endpoint("/messages/outbound")?)
+            .query(filter);
+
+        self.execute(request).await
+    }
+
+    #[tracing::instrument(
+        name = "Get outbound message details using postmark client",
+        skip(self)
+    )]
+    pub async fn get_outbound_message(
+        &self,
+        message_id: &str,
+    ) -> Result<OutboundMessageDetails, ClientError> {
+        let message_id = path_segment(message_id)?;
+        let request = self
+            .http_client
+            .get(self.endpoint(&format!("/messages/outbound/{message_id}/details"))?);
+
+        self.execute(request).await
+    }
+
+    #[tracing::instrument(name = "Get outbound message dump using postmark client", skip(self))]
+    pub async fn get_outbound_message_dump(
+        &self,
+        message_id: &str,
+    ) -> Result<MessageDump, ClientError> {
+        let message_id = path_segment(message_id)?;
+        let request = self
+            .http_client
+            .get(self.endpoint(&format!("/messages/outbound/{message_id}/dump"))?);
+
+        self.execute(request).await
+    }
+
+    #[tracing::instrument(name = "Search opens using postmark client", skip(self))]
+    pub async fn search_opens(
+        &self,
+        filter: &TrackingEventFilter,
+    ) -> Result<OpenEventList, ClientError> {
+        let request = self
+            .http_client
+            .get(self.endpoint("/messages/outbound/opens")?)
+            .query(filter);
+
+        self.execute(request).await
+    }
+
+    #[tracing::instrument(name = "Get message opens using postmark client", skip(self))]
+    pub async fn get_message_opens(
+        &self,
+        message_id: &str,
+        count: u32,
+        offset: u32,
+    ) -> Result<OpenEventList, ClientError> {
+        let message_id = path_segment(message_id)?;
+        let request = self
+            .http_client
+            .get(self.endpoint(&format!("/messages/outbound/opens/{message_id}"))?)
+            .query(&PageQuery { count, offset });
+
+        self.execute(request).await
+    }
+
+    #[tracing::instrument(name = "Search clicks using postmark client", skip(self))]
+    pub async fn search_clicks(
+        &self,
+        filter: &TrackingEventFilter,
+    ) -> Result<ClickEventList, ClientError> {
+        let request = self
+            .http_client
+            .get(self.endpoint("/messages/outbound/clicks")?)
+            .query(filter);
+
+        self.execute(request).await
+    }
+
+    #[tracing::instrument(name = "Get message clicks using postmark client", skip(self))]
+    pub async fn get_message_clicks(
+        &self,
+        message_id: &str,
+        count: u32,
+        offset: u32,
+    ) -> Result<ClickEventList, ClientError> {
+        let message_id = path_segment(message_id)?;
+        let request = self
+            .http_client
+            .get(self.endpoint(&format!("/messages/outbound/clicks/{message_id}"))?)
+            .query(&PageQuery { count, offset });
+
+        self.execute(request).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_outbound_message_details_deserialization() {
+        let details: OutboundMessageDetails = serde_json::from_value(json!({
+            "TextBody": "Thank you for your order...",
+            "HtmlBody": "<p>Thank you for your order...</p>",
+            "Body": "SMTP dump data",
+            "Tag": "product-orders",
+            "MessageID": "07311c54-0687-4ab9-b034-b54b5bad88ba",
+            "MessageStream": "outbound",
+            "To": [{ "Email": "john.doe@yahoo.com", "Name": "John Doe" }],
+            "Cc": [],
+            "Bcc": [],
+            "Recipients": ["john.doe@yahoo.com"],
+            "ReceivedAt": "2014-02-14T11:12:54.8054242-05:00",
+            "From": "\"Joe\" <joe@domain.com>",
+            "Subject": "Parts Order #5454",
+            "Attachments": ["myfile.txt"],
+            "Status": "Sent",
+            "TrackOpens": true,
+            "TrackLinks": "HtmlOnly",
+            "Metadata": { "color": "blue" },
+            "Sandboxed": false,
+            "MessageEvents": [
+                {
+                    "Recipient": "john.doe@yahoo.com",
+                    "Type": "Delivered",
+                    "ReceivedAt": "2014-02-14T11:13:03-05:00",
+                    "Details": {
+                        "DeliveryMessage": "smtp;250 2.0.0 OK",
+                        "DestinationServer": "yahoo-smtp-in.l.yahoo.com",
+                        "DestinationIP": "173.194.74.256"
+                    }
+                },
+                {
+                    "Recipient": "john.doe@yahoo.com",
+                    "Type": "Rescheduled",
+                    "ReceivedAt": "2014-02-14T11:14:03-05:00",
+                    "Details": {}
+                }
+            ]
+        }))
+        .unwrap();
+
+        assert_eq!(details.message.status, OutboundMessageStatus::Sent);
+        assert_eq!(details.message.track_links, TrackLink::HtmlOnly);
+        assert_eq!(details.message.to[0].name.as_deref(), Some("John Doe"));
+        assert_eq!(details.message.metadata["color"], "blue");
+        assert_eq!(
+            details.message_events[0].details.destination_ip.as_deref(),
+            Some("173.194.74.256")
+        );
+        assert_eq!(
+            details.message_events[1].event_type,
+            MessageEventType::Other
+        );
+    }
+
+    #[test]
+    fn test_open_event_deserialization() {
+        let open: OpenEvent = serde_json::from_value(json!({
+            "RecordType": "Open",
+            "Client": { "Name": "Chrome 35.0.1916.153", "Company": "Google", "Family": "Chrome" },
+            "OS": { "Name": "OS X 10.7 Lion", "Company": "Apple Computer, Inc.", "Family": "OS X 10" },
+            "Platform": "WebMail",
+            "UserAgent": "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_7_5)",
+            "Geo": {
+                "CountryISOCode": "RS",
+                "Country": "Serbia",
+                "RegionISOCode": "VO",
+                "Region": "Autonomna Pokrajina Vojvodina",
+                "City": "Novi Sad",
+                "Zip": "21000",
+                "Coords": "45.2517,19.8369",
+                "IP": "188.2.95.4"
+            },
+            "MessageID": "927e56d2-dc08-4576-a5a8-22e8f7e0c1a5",
+            "MessageStream": "outbound",
+            "ReceivedAt": "2014-02-14T11:12:54.8054242-05:00",
+            "Tag": "welcome-email",
+            "Recipient": "john@domain.com",
+            "FirstOpen": true,
+            "ReadSeconds": 16
+        }))
+        .unwrap();
+
+        assert_eq!(open.platform, Platform::WebMail);
+        assert_eq!(open.os.family.as_deref(), Some("OS X 10"));
+        assert_eq!(open.geo.country_iso_code.as_deref(), Some("RS"));
+        assert!(open.first_open);
+    }
+
+    #[test]
+    fn test_click_event_deserialization() {
+        let click: ClickEvent = serde_json::from_value(json!({
+            "RecordType": "Click",
+            "ClickLocation": "HTML",
+            "Client": { "Name": "Chrome 35.0.1916.153", "Company": "Google", "Family": "Chrome" },
+            "OS": { "Name": "OS X 10.7 Lion", "Company": "Apple Computer, Inc.", "Family": "OS X 10" },
+            "Platform": "Tablet",
+            "UserAgent": "Mozilla/5.0",
+            "OriginalLink": "https://example.com/pricing",
+            "Geo": {},
+            "MessageID": "927e56d2-dc08-4576-a5a8-22e8f7e0c1a5",
+            "MessageStream": "outbound",
+            "ReceivedAt": "2014-02-14T11:12:54.8054242-05:00",
+            "Tag": "welcome-email",
+            "Recipient": "john@domain.com"
+        }))
+        .unwrap();
+
+        assert_eq!(click.click_location, ClickLocation::Html);
+        assert_eq!(click.platform, Platform::Unknown);
+        assert_eq!(click.original_link, "https://example.com/pricing");
+    }
+
+    #[test]
+    fn test_filter_serializes_metadata_keys() {
+        let filter = OutboundMessageFilter::new()
+            .status(OutboundMessageStatus::Sent)
+            .metadata("color", "blue");
+
+        let query = serde_json::to_value(&filter).unwrap();
+
+        assert_eq!(query["status"], "sent");
+        assert_eq!(query["metadata_color"], "blue");
+    }
+
+    #[test]
+    fn test_unknown_status_is_read_but_never_sent() {
+        let status: OutboundMessageStatus = serde_json::from_value(json!("bounced")).unwrap();
+        assert_eq!(status, OutboundMessageStatus::Other);
+
+        let filter = OutboundMessageFilter::new().status(status);
+        assert!(serde_json::to_value(&filter).is_err());
+    }
+}
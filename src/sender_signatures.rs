@@ -0,0 +1,383 @@
+use crate::error::ClientError;
+use crate::{visit_pages, AccountClient, ApiResponse, DkimUpdateStatus, DomainFilter, Email};
+use serde::{Deserialize, Serialize};
+use std::ops::ControlFlow;
+
+/// A sender signature as listed by `GET /senders`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SenderSignatureSummary {
+    #[serde(rename = "ID")]
+    pub id: i64,
+    pub domain: String,
+    pub email_address: String,
+    pub reply_to_email_address: String,
+    pub name: String,
+    pub confirmed: bool,
+}
+
+impl SenderSignatureSummary {
+    /// Whether mail can be sent from `email` with this signature.
+    pub fn covers(&self, email: &Email) -> bool {
+        self.confirmed && self.email_address.eq_ignore_ascii_case(email.as_ref())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SenderSignatureList {
+    pub total_count: u32,
+    pub sender_signatures: Vec<SenderSignatureSummary>,
+}
+
+/// A sender signature with the DNS records of its domain, as returned by
+/// `GET /senders/{id}`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SenderSignature {
+    #[serde(rename = "ID")]
+    pub id: i64,
+    #[serde(rename = "Domain")]
+    pub domain: String,
+    #[serde(rename = "EmailAddress")]
+    pub email_address: String,
+    #[serde(rename = "ReplyToEmailAddress", default)]
+    pub reply_to_email_address: String,
+    #[serde(rename = "Name")]
+    pub name: String,
+    #[serde(rename = "Confirmed")]
+    pub confirmed: bool,
+    #[serde(rename = "DKIMVerified", default)]
+    pub dkim_verified: bool,
+    #[serde(rename = "WeakDKIM", default)]
+    pub weak_dkim: bool,
+    #[serde(rename = "DKIMHost")]
+    pub dkim_host: Option<String>,
+    #[serde(rename = "DKIMTextValue")]
+    pub dkim_text_value: Option<String>,
+    #[serde(rename = "DKIMPendingHost")]
+    pub dkim_pending_host: Option<String>,
+    #[serde(rename = "DKIMPendingTextValue")]
+    pub dkim_pending_text_value: Option<String>,
+    #[serde(rename = "DKIMRevokedHost")]
+    pub dkim_revoked_host: Option<String>,
+    #[serde(rename = "DKIMRevokedTextValue")]
+    pub dkim_revoked_text_value: Option<String>,
+    #[serde(rename = "SafeToRemoveRevokedKeyFromDNS", default)]
+    pub safe_to_remove_revoked_key_from_dns: bool,
+    #[serde(rename = "DKIMUpdateStatus")]
+    pub dkim_update_status: Option<DkimUpdateStatus>,
+    #[serde(rename = "ReturnPathDomain")]
+    pub return_path_domain: Option<String>,
+    #[serde(rename = "ReturnPathDomainVerified", default)]
+    pub return_path_domain_verified: bool,
+    #[serde(rename = "ReturnPathDomainCNAMEValue")]
+    pub return_path_domain_cname_value: Option<String>,
+    #[serde(rename = "ConfirmationPersonalNote")]
+    pub confirmation_personal_note: Option<String>,
+}
+
+/// Query parameters for `GET /senders`.
+#[derive(Debug, Clone, Serialize)]
+pub struct SenderSignatureFilter {
+    count: u32,
+    offset: u32,
+}
+
+impl Default for SenderSignatureFilter {
+    fn default() -> Self {
+        Self {
+            count: 100,
+            offset: 0,
+        }
+    }
+}
+
+impl SenderSignatureFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn count(mut self, count: u32) -> Self {
+        self.count = count;
+        self
+    }
+
+    pub fn offset(mut self, offset: u32) -> Self {
+        self.offset = offset;
+        self
+    }
+}
+
+/// The fields sent when creating or editing a sender signature. The from
+/// address can only be set on creation; fields left unset are omitted.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SenderSignatureBody {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) from_email: Option<Email>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) reply_to_email: Option<Email>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) return_path_domain: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) confirmation_personal_note: Option<String>,
+}
+
+impl SenderSignatureBody {
+    pub fn builder() -> SenderSignatureBodyBuilder {
+        SenderSignatureBodyBuilder::new()
+    }
+}
+
+// The builder for SenderSignatureBody
+#[derive(Debug, Clone, Default)]
+pub struct SenderSignatureBodyBuilder {
+    body: SenderSignatureBody,
+}
+
+impl SenderSignatureBodyBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn from_email(mut self, from_email: Email) -> Self {
+        self.body.from_email = Some(from_email);
+        self
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.body.name = Some(name.into());
+        self
+    }
+
+    pub fn reply_to_email(mut self, reply_to_email: Email) -> Self {
+        self.body.reply_to_email = Some(reply_to_email);
+        self
+    }
+
+    pub fn return_path_domain(mut self, return_path_domain: impl Into<String>) -> Self {
+        self.body.return_path_domain = Some(return_path_domain.into());
+        self
+    }
+
+    /// A note included in the confirmation email sent to the address.
+    pub fn confirmation_personal_note(mut self, note: impl Into<String>) -> Self {
+        self.body.confirmation_personal_note = Some(note.into());
+        self
+    }
+
+    pub fn build(self) -> SenderSignatureBody {
+        self.body
+    }
+}
+
+impl AccountClient {
+    #[tracing::instrument(name = "List sender signatures using postmark client", skip(self))]
+    pub async fn list_sender_signatures(
+        &self,
+        filter: &SenderSignatureFilter,
+    ) -> Result<SenderSignatureList, ClientError> {
+        let request = self
+            .http_client
+            .get(self.endpoint("/senders")?)
+            .query(filter);
+
+        self.execute(request).await
+    }
+
+    #[tracing::instrument(name = "Get sender signature using postmark client", skip(self))]
+    pub async fn get_sender_signature(
+        &self,
+        signature_id: i64,
+    ) -> Result<SenderSignature, ClientError> {
+        let request = self
+            .http_client
+            .get(self.endpoint(&format!("/senders/{signature_id}"))?);
+
+        self.execute(request).await
+    }
+
+    /// Creates a sender signature. Postmark sends a confirmation email to
+    /// the address, and the signature can't be used until it is confirmed.
+    #[tracing::instrument(
+        name = "Create sender signature using postmark client",
+        skip(self, body)
+    )]
+    pub async fn create_sender_signature(
+        &self,
+        body: &SenderSignatureBody,
+    ) -> Result<SenderSignature, ClientError> {
+        let request = self.http_client.post(self.endpoint("/senders")?).json(body);
+
+        self.execute(request).await
+    }
+
+    #[tracing::instrument(name = "Edit sender signature using postmark client", skip(self, body))]
+    pub async fn edit_sender_signature(
+        &self,
+        signature_id: i64,
+        body: &SenderSignatureBody,
+    ) -> Result<SenderSignature, ClientError> {
+        let request = self
+            .http_client
+            .put(self.endpoint(&format!("/senders/{signature_id}"))?)
+            .json(body);
+
+        self.execute(request).await
+    }
+
+    #[tracing::instrument(name = "Delete sender signature using postmark client", skip(self))]
+    pub async fn delete_sender_signature(
+        &self,
+        signature_id: i64,
+    ) -> Result<ApiResponse, ClientError> {
+        let request = self
+            .http_client
+            .delete(self.endpoint(&format!("/senders/{signature_id}"))?);
+
+        self.execute(request).await
+    }
+
+    #[tracing::instrument(
+        name = "Resend sender signature confirmation using postmark client",
+        skip(self)
+    )]
+    pub async fn resend_sender_confirmation(
+        &self,
+        signature_id: i64,
+    ) -> Result<ApiResponse, ClientError> {
+        let request = self
+            .http_client
+            .post(self.endpoint(&format!("/senders/{signature_id}/resend"))?);
+
+        self.execute(request).await
+    }
+
+    /// Whether Postmark accepts `sender` as a from address, i.e. it has a
+    /// confirmed sender signature or belongs to a verified domain. Meant to
+    /// be checked before building a [`crate::Client`] with it.
+    #[tracing::instrument(name = "Check sender using postmark client", skip(self))]
+    pub async fn is_sender_covered(&self, sender: &Email) -> Result<bool, ClientError> {
+        let mut covered = false;
+
+        visit_pages(
+            |offset| async move {
+                let page = self
+                    .list_sender_signatures(&SenderSignatureFilter::new().offset(offset))
+                    .await?;
+                Ok((page.sender_signatures, page.total_count))
+            },
+            |signatures| {
+                covered = signatures.iter().any(|signature| signature.covers(sender));
+                stop_if(covered)
+            },
+        )
+        .await?;
+        if covered {
+            return Ok(true);
+        }
+
+        visit_pages(
+            |offset| async move {
+                let page = self
+                    .list_domains(&DomainFilter::new().offset(offset))
+                    .await?;
+                Ok((page.domains, page.total_count))
+            },
+            |domains| {
+                covered = domains.iter().any(|domain| domain.covers(sender));
+                stop_if(covered)
+            },
+        )
+        .await?;
+
+        Ok(covered)
+    }
+}
+
+fn stop_if(done: bool) -> ControlFlow<()> {
+    if done {
+        ControlFlow::Break(())
+    } else {
+        ControlFlow::Continue(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn signature(email_address: &str, confirmed: bool) -> SenderSignatureSummary {
+        SenderSignatureSummary {
+            id: 1,
+            domain: "example.com".to_string(),
+            email_address: email_address.to_string(),
+            reply_to_email_address: String::new(),
+            name: "Example".to_string(),
+            confirmed,
+        }
+    }
+
+    #[test]
+    fn test_only_confirmed_signatures_cover_their_address() {
+        let sender = Email::parse("jp@example.com").unwrap();
+
+        assert!(signature("JP@example.com", true).covers(&sender));
+        assert!(!signature("jp@example.com", false).covers(&sender));
+        assert!(!signature("info@example.com", true).covers(&sender));
+    }
+
+    #[test]
+    fn test_sender_signature_body_serialization() {
+        let body = SenderSignatureBody::builder()
+            .from_email(Email::parse("jp@example.com").unwrap())
+            .name("JP Toto")
+            .confirmation_personal_note("Please confirm")
+            .build();
+
+        let value = serde_json::to_value(&body).unwrap();
+
+        assert_eq!(
+            value,
+            json!({
+                "FromEmail": "jp@example.com",
+                "Name": "JP Toto",
+                "ConfirmationPersonalNote": "Please confirm"
+            })
+        );
+    }
+
+    #[test]
+    fn test_unconfirmed_signature_with_null_records() {
+        let signature: SenderSignature = serde_json::from_value(json!({
+            "Domain": "example.com",
+            "EmailAddress": "jp@example.com",
+            "ReplyToEmailAddress": "",
+            "Name": "JP Toto",
+            "Confirmed": false,
+            "DKIMVerified": false,
+            "WeakDKIM": false,
+            "DKIMHost": null,
+            "DKIMTextValue": null,
+            "DKIMPendingHost": null,
+            "DKIMPendingTextValue": null,
+            "DKIMRevokedHost": null,
+            "DKIMRevokedTextValue": null,
+            "SafeToRemoveRevokedKeyFromDNS": false,
+            "DKIMUpdateStatus": null,
+            "ReturnPathDomain": null,
+            "ReturnPathDomainVerified": false,
+            "ReturnPathDomainCNAMEValue": null,
+            "ConfirmationPersonalNote": null,
+            "ID": 36735
+        }))
+        .unwrap();
+
+        assert!(!signature.confirmed);
+        assert_eq!(signature.dkim_host, None);
+        assert_eq!(signature.return_path_domain, None);
+    }
+}
@@ -0,0 +1,430 @@
+use crate::error::ClientError;
+use crate::{AccountClient, ApiResponse, Client, TrackLink};
+use secrecy::SecretString;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DeliveryType {
+    Live,
+    Sandbox,
+}
+
+/// The settings of a server, as returned by `GET /server` and the account
+/// servers API.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ServerSettings {
+    #[serde(rename = "ID")]
+    pub id: i64,
+    pub name: String,
+    /// The server tokens that can be used to build a [`Client`].
+    #[serde(default)]
+    pub api_tokens: Vec<SecretString>,
+    pub color: Option<String>,
+    pub smtp_api_activated: bool,
+    pub raw_email_enabled: bool,
+    pub delivery_type: DeliveryType,
+    pub server_link: Option<String>,
+    pub inbound_address: Option<String>,
+    pub inbound_hash: Option<String>,
+    pub inbound_domain: Option<String>,
+    pub inbound_spam_threshold: u32,
+    pub inbound_hook_url: Option<String>,
+    pub bounce_hook_url: Option<String>,
+    pub open_hook_url: Option<String>,
+    pub delivery_hook_url: Option<String>,
+    pub click_hook_url: Option<String>,
+    pub post_first_open_only: bool,
+    pub track_opens: bool,
+    pub track_links: TrackLink,
+    pub include_bounce_content_in_hook: bool,
+    #[serde(default)]
+    pub enable_smtp_api_error_hooks: bool,
+}
+
+impl ServerSettings {
+    /// The first server token, the one Postmark creates with the server.
+    pub fn api_token(&self) -> Option<&SecretString> {
+        self.api_tokens.first()
+    }
+}
+
+/// The fields sent when creating or editing a server. Fields left unset are
+/// omitted, so an edit only touches what was explicitly provided. The
+/// delivery type is only sent when creating a server.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ServerBody {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) color: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) smtp_api_activated: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) raw_email_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) delivery_type: Option<DeliveryType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) inbound_domain: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) inbound_spam_threshold: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) inbound_hook_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) bounce_hook_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) open_hook_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) delivery_hook_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) click_hook_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) post_first_open_only: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) track_opens: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) track_links: Option<TrackLink>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) include_bounce_content_in_hook: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) enable_smtp_api_error_hooks: Option<bool>,
+}
+
+impl ServerBody {
+    pub fn builder() -> ServerBodyBuilder {
+        ServerBodyBuilder::new()
+    }
+
+    // A server can't be switched between live and sandbox mode, so edits
+    // leave the delivery type out.
+    pub(crate) fn for_edit(&self) -> ServerBody {
+        ServerBody {
+            delivery_type: None,
+            ..self.clone()
+        }
+    }
+}
+
+// The builder for ServerBody
+#[derive(Debug, Clone, Default)]
+pub struct ServerBodyBuilder {
+    body: ServerBody,
+}
+
+impl ServerBodyBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.body.name = Some(name.into());
+        self
+    }
+
+    /// The color of the server in the Postmark UI, e.g. `Purple` or `Green`.
+    pub fn color(mut self, color: impl Into<String>) -> Self {
+        self.body.color = Some(color.into());
+        self
+    }
+
+    pub fn smtp_api_activated(mut self, smtp_api_activated: bool) -> Self {
+        self.body.smtp_api_activated = Some(smtp_api_activated);
+        self
+    }
+
+    pub fn raw_email_enabled(mut self, raw_email_enabled: bool) -> Self {
+        self.body.raw_email_enabled = Some(raw_email_enabled);
+        self
+    }
+
+    /// Only used when creating a server, which can't be switched between
+    /// live and sandbox mode afterwards. Edits leave it out.
+    pub fn delivery_type(mut self, delivery_type: DeliveryType) -> Self {
+        self.body.delivery_type = Some(delivery_type);
+        self
+    }
+
+    pub fn inbound_domain(mut self, inbound_domain: impl Into<String>) -> Self {
+        self.body.inbound_domain = Some(inbound_domain.into());
+        self
+    }
+
+    /// The spam score from which inbound messages are blocked.
+    pub fn inbound_spam_threshold(mut self, inbound_spam_threshold: u32) -> Self {
+        self.body.inbound_spam_threshold = Some(inbound_spam_threshold);
+        self
+    }
+
+    pub fn inbound_hook_url(mut self, url: impl Into<String>) -> Self {
+        self.body.inbound_hook_url = Some(url.into());
+        self
+    }
+
+    pub fn bounce_hook_url(mut self, url: impl Into<String>) -> Self {
+        self.body.bounce_hook_url = Some(url.into());
+        self
+    }
+
+    pub fn open_hook_url(mut self, url: impl Into<String>) -> Self {
+        self.body.open_hook_url = Some(url.into());
+        self
+    }
+
+    pub fn delivery_hook_url(mut self, url: impl Into<String>) -> Self {
+        self.body.delivery_hook_url = Some(url.into());
+        self
+    }
+
+    pub fn click_hook_url(mut self, url: impl Into<String>) -> Self {
+        self.body.click_hook_url = Some(url.into());
+        self
+    }
+
+    pub fn post_first_open_only(mut self, post_first_open_only: bool) -> Self {
+        self.body.post_first_open_only = Some(post_first_open_only);
+        self
+    }
+
+    pub fn track_opens(mut self, track_opens: bool) -> Self {
+        self.body.track_opens = Some(track_opens);
+        self
+    }
+
+    pub fn track_links(mut self, track_links: TrackLink) -> Self {
+        self.body.track_links = Some(track_links);
+        self
+    }
+
+    pub fn include_bounce_content_in_hook(mut self, include: bool) -> Self {
+        self.body.include_bounce_content_in_hook = Some(include);
+        self
+    }
+
+    pub fn enable_smtp_api_error_hooks(mut self, enable: bool) -> Self {
+        self.body.enable_smtp_api_error_hooks = Some(enable);
+        self
+    }
+
+    pub fn build(self) -> ServerBody {
+        self.body
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ServerList {
+    pub total_count: u32,
+    pub servers: Vec<ServerSettings>,
+}
+
+/// Query parameters for `GET /servers`.
+#[derive(Debug, Clone, Serialize)]
+pub struct ServerFilter {
+    count: u32,
+    offset: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+}
+
+impl Default for ServerFilter {
+    fn default() -> Self {
+        Self {
+            count: 100,
+            offset: 0,
+            name: None,
+        }
+    }
+}
+
+impl ServerFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn count(mut self, count: u32) -> Self {
+        self.count = count;
+        self
+    }
+
+    pub fn offset(mut self, offset: u32) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Only servers whose name contains `name`.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+}
+
+impl Client {
+    /// Fetches the settings of the server the client's token belongs to.
+    #[tracing::instrument(name = "Get server using postmark client", skip(self))]
+    pub async fn get_server(&self) -> Result<ServerSettings, ClientError> {
+        let request = self.http_client.get(self.endpoint("/server")?);
+
+        self.execute(request).await
+    }
+
+    #[tracing::instrument(name = "Edit server using postmark client", skip(self, body))]
+    pub async fn edit_server(&self, body: &ServerBody) -> Result<ServerSettings, ClientError> {
+        let request = self
+            .http_client
+            .put(self.endpoint("/server")?)
+            .json(&body.for_edit());
+
+        self.execute(request).await
+    }
+}
+
+impl AccountClient {
+    #[tracing::instrument(name = "List servers using postmark client", skip(self))]
+    pub async fn list_servers(&self, filter: &ServerFilter) -> Result<ServerList, ClientError> {
+        let request = self
+            .http_client
+            .get(self.endpoint("/servers")?)
+            .query(filter);
+
+        self.execute(request).await
+    }
+
+    #[tracing::instrument(name = "Get account server using postmark client", skip(self))]
+    pub async fn get_server(&self, server_id: i64) -> Result<ServerSettings, ClientError> {
+        let request = self
+            .http_client
+            .get(self.endpoint(&format!("/servers/{server_id}"))?);
+
+        self.execute(request).await
+    }
+
+    /// Creates a server. The body needs at least a name.
+    #[tracing::instrument(name = "Create server using postmark client", skip(self, body))]
+    pub async fn create_server(&self, body: &ServerBody) -> Result<ServerSettings, ClientError> {
+        let request = self.http_client.post(self.endpoint("/servers")?).json(body);
+
+        self.execute(request).await
+    }
+
+    /// Edits a server. The delivery type of `body` is left out, as it can
+    /// only be set on creation.
+    #[tracing::instrument(name = "Edit account server using postmark client", skip(self, body))]
+    pub async fn edit_server(
+        &self,
+        server_id: i64,
+        body: &ServerBody,
+    ) -> Result<ServerSettings, ClientError> {
+        let request = self
+            .http_client
+            .put(self.endpoint(&format!("/servers/{server_id}"))?)
+            .json(&body.for_edit());
+
+        self.execute(request).await
+    }
+
+    /// Deletes a server. Postmark only allows this once deleting servers
+    /// through the API was enabled for the account.
+    #[tracing::instrument(name = "Delete server using postmark client", skip(self))]
+    pub async fn delete_server(&self, server_id: i64) -> Result<ApiResponse, ClientError> {
+        let request = self
+            .http_client
+            .delete(self.endpoint(&format!("/servers/{server_id}"))?);
+
+        self.execute(request).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use secrecy::ExposeSecret;
+    use serde_json::json;
+
+    #[test]
+    fn test_server_settings_deserialization() {
+        let server: ServerSettings = serde_json::from_value(json!({
+            "ID": 1,
+            "Name": "Staging Testing",
+            "ApiTokens": ["server token"],
+            "Color": "red",
+            "SmtpApiActivated": true,
+            "RawEmailEnabled": false,
+            "DeliveryType": "Live",
+            "ServerLink": "https://postmarkapp.com/servers/1/streams",
+            "InboundAddress": "yourhash@inbound.postmarkapp.com",
+            "InboundHookUrl": "http://hooks.example.com/inbound",
+            "BounceHookUrl": "http://hooks.example.com/bounce",
+            "OpenHookUrl": "http://hooks.example.com/open",
+            "DeliveryHookUrl": "http://hooks.example.com/delivery",
+            "PostFirstOpenOnly": false,
+            "InboundDomain": "",
+            "InboundHash": "yourhash",
+            "InboundSpamThreshold": 5,
+            "TrackOpens": false,
+            "TrackLinks": "None",
+            "IncludeBounceContentInHook": true,
+            "ClickHookUrl": "http://hooks.example.com/click",
+            "EnableSmtpApiErrorHooks": false
+        }))
+        .unwrap();
+
+        assert_eq!(server.delivery_type, DeliveryType::Live);
+        assert_eq!(server.track_links, TrackLink::None);
+        assert_eq!(server.api_token().unwrap().expose_secret(), "server token");
+        assert!(!format!("{server:?}").contains("server token"));
+    }
+
+    #[test]
+    fn test_server_body_only_serializes_set_fields() {
+        let body = ServerBody::builder()
+            .track_opens(true)
+            .track_links(TrackLink::HtmlOnly)
+            .build();
+
+        let value = serde_json::to_value(&body).unwrap();
+
+        assert_eq!(
+            value,
+            json!({ "TrackOpens": true, "TrackLinks": "HtmlOnly" })
+        );
+    }
+
+    #[test]
+    fn test_edit_body_leaves_out_the_delivery_type() {
+        let body = ServerBody::builder()
+            .name("Staging")
+            .delivery_type(DeliveryType::Sandbox)
+            .build();
+
+        let value = serde_json::to_value(body.for_edit()).unwrap();
+
+        assert_eq!(value, json!({ "Name": "Staging" }));
+    }
+
+    #[test]
+    fn test_server_list_deserialization() {
+        let list: ServerList = serde_json::from_value(json!({
+            "TotalCount": 1,
+            "Servers": [{
+                "ID": 11635,
+                "Name": "Production01",
+                "ApiTokens": ["fa1d3f5a-8d4c-4b5a-9d6a-1a2b3c4d5e6f"],
+                "Color": "purple",
+                "SmtpApiActivated": true,
+                "RawEmailEnabled": false,
+                "DeliveryType": "Sandbox",
+                "InboundSpamThreshold": 0,
+                "PostFirstOpenOnly": false,
+                "TrackOpens": false,
+                "TrackLinks": "None",
+                "IncludeBounceContentInHook": true
+            }]
+        }))
+        .unwrap();
+
+        assert_eq!(list.total_count, 1);
+        assert_eq!(list.servers[0].delivery_type, DeliveryType::Sandbox);
+        assert_eq!(list.servers[0].api_tokens.len(), 1);
+    }
+}
@@ -0,0 +1,325 @@
+use crate::error::ClientError;
+use crate::Client;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// The totals for all outbound messages, as returned by `GET /stats/outbound`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct OutboundOverview {
+    pub sent: u64,
+    pub bounced: u64,
+    #[serde(rename = "SMTPApiErrors")]
+    pub smtp_api_errors: u64,
+    /// The percentage of sent messages that bounced.
+    pub bounce_rate: f64,
+    pub spam_complaints: u64,
+    /// The percentage of sent messages that were marked as spam.
+    pub spam_complaints_rate: f64,
+    pub opens: u64,
+    pub unique_opens: u64,
+    pub tracked: u64,
+    pub with_link_tracking: u64,
+    pub with_open_tracking: u64,
+    pub total_tracked_links_sent: u64,
+    pub unique_links_clicked: u64,
+    pub total_clicks: u64,
+    pub with_client_recorded: u64,
+    pub with_platform_recorded: u64,
+    pub with_read_time_recorded: u64,
+}
+
+/// A stats response: the counts for each day of the range along with their
+/// totals. Postmark omits days without any activity.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StatsSeries<T> {
+    #[serde(rename = "Days", default)]
+    pub days: Vec<DailyStats<T>>,
+    #[serde(flatten)]
+    pub totals: T,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DailyStats<T> {
+    /// The day, e.g. `2024-01-31`.
+    #[serde(rename = "Date")]
+    pub date: String,
+    #[serde(flatten)]
+    pub counts: T,
+}
+
+// Postmark leaves out the counts that are zero, hence the defaults below.
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct SentCounts {
+    pub sent: u64,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct BounceCounts {
+    pub hard_bounce: u64,
+    pub soft_bounce: u64,
+    #[serde(rename = "SMTPApiError")]
+    pub smtp_api_error: u64,
+    pub transient: u64,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct SpamComplaintCounts {
+    pub spam_complaint: u64,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct TrackedEmailCounts {
+    pub tracked: u64,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct OpenCounts {
+    pub opens: u64,
+    pub unique: u64,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct PlatformCounts {
+    pub desktop: u64,
+    pub mobile: u64,
+    pub web_mail: u64,
+    pub unknown: u64,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct ClickCounts {
+    pub clicks: u64,
+    pub unique: u64,
+}
+
+/// Clicks by the body part the link was in.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ClickLocationCounts {
+    #[serde(rename = "HTML")]
+    pub html: u64,
+    #[serde(rename = "Text")]
+    pub text: u64,
+}
+
+/// Counts keyed by a name Postmark reports as is, such as an email client
+/// (`Apple Mail`) or a browser family (`Google Chrome`).
+pub type UsageCounts = BTreeMap<String, u64>;
+
+/// Query parameters shared by the `/stats/outbound` endpoints.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct StatsFilter {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tag: Option<String>,
+    #[serde(rename = "fromdate", skip_serializing_if = "Option::is_none")]
+    from_date: Option<String>,
+    #[serde(rename = "todate", skip_serializing_if = "Option::is_none")]
+    to_date: Option<String>,
+    #[serde(rename = "messagestream", skip_serializing_if = "Option::is_none")]
+    message_stream: Option<String>,
+}
+
+impl StatsFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn tag(mut self, tag: impl Into<String>) -> Self {
+        self.tag = Some(tag.into());
+        self
+    }
+
+    /// Only stats from this date on, e.g. `2024-01-31`.
+    pub fn from_date(mut self, from_date: impl Into<String>) -> Self {
+        self.from_date = Some(from_date.into());
+        self
+    }
+
+    /// Only stats up to this date, e.g. `2024-02-29`.
+    pub fn to_date(mut self, to_date: impl Into<String>) -> Self {
+        self.to_date = Some(to_date.into());
+        self
+    }
+
+    pub fn message_stream(mut self, message_stream: impl Into<String>) -> Self {
+        self.message_stream = Some(message_stream.into());
+        self
+    }
+}
+
+impl Client {
+    #[tracing::instrument(name = "Get outbound overview using postmark client", skip(self))]
+    pub async fn outbound_overview(
+        &self,
+        filter: &StatsFilter,
+    ) -> Result<OutboundOverview, ClientError> {
+        self.outbound_stats("/stats/outbound", filter).await
+    }
+
+    #[tracing::instrument(name = "Get sent counts using postmark client", skip(self))]
+    pub async fn sent_counts(
+        &self,
+        filter: &StatsFilter,
+    ) -> Result<StatsSeries<SentCounts>, ClientError> {
+        self.outbound_stats("/stats/outbound/sends", filter).await
+    }
+
+    #[tracing::instrument(name = "Get bounce counts using postmark client", skip(self))]
+    pub async fn bounce_counts(
+        &self,
+        filter: &StatsFilter,
+    ) -> Result<StatsSeries<BounceCounts>, ClientError> {
+        self.outbound_stats("/stats/outbound/bounces", filter).await
+    }
+
+    #[tracing::instrument(name = "Get spam complaint counts using postmark client", skip(self))]
+    pub async fn spam_complaint_counts(
+        &self,
+        filter: &StatsFilter,
+    ) -> Result<StatsSeries<SpamComplaintCounts>, ClientError> {
+        self.outbound_stats("/stats/outbound/spam", filter).await
+    }
+
+    /// Counts of the messages sent with open or link tracking.
+    #[tracing::instrument(name = "Get tracked email counts using postmark client", skip(self))]
+    pub async fn tracked_email_counts(
+        &self,
+        filter: &StatsFilter,
+    ) -> Result<StatsSeries<TrackedEmailCounts>, ClientError> {
+        self.outbound_stats("/stats/outbound/tracked", filter).await
+    }
+
+    #[tracing::instrument(name = "Get open counts using postmark client", skip(self))]
+    pub async fn open_counts(
+        &self,
+        filter: &StatsFilter,
+    ) -> Result<StatsSeries<OpenCounts>, ClientError> {
+        self.outbound_stats("/stats/outbound/opens", filter).await
+    }
+
+    #[tracing::instrument(name = "Get open platform usage using postmark client", skip(self))]
+    pub async fn open_platform_usage(
+        &self,
+        filter: &StatsFilter,
+    ) -> Result<StatsSeries<PlatformCounts>, ClientError> {
+        self.outbound_stats("/stats/outbound/opens/platforms", filter)
+            .await
+    }
+
+    #[tracing::instrument(name = "Get open email client usage using postmark client", skip(self))]
+    pub async fn open_email_client_usage(
+        &self,
+        filter: &StatsFilter,
+    ) -> Result<StatsSeries<UsageCounts>, ClientError> {
+        self.outbound_stats("/stats/outbound/opens/emailclients", filter)
+            .await
+    }
+
+    #[tracing::instrument(name = "Get click counts using postmark client", skip(self))]
+    pub async fn click_counts(
+        &self,
+        filter: &StatsFilter,
+    ) -> Result<StatsSeries<ClickCounts>, ClientError> {
+        self.outbound_stats("/stats/outbound/clicks", filter).await
+    }
+
+    #[tracing::instrument(name = "Get click browser usage using postmark client", skip(self))]
+    pub async fn click_browser_usage(
+        &self,
+        filter: &StatsFilter,
+    ) -> Result<StatsSeries<UsageCounts>, ClientError> {
+        self.outbound_stats("/stats/outbound/clicks/browserfamilies", filter)
+            .await
+    }
+
+    #[tracing::instrument(name = "Get click location using postmark client", skip(self))]
+    pub async fn click_location(
+        &self,
+        filter: &StatsFilter,
+    ) -> Result<StatsSeries<ClickLocationCounts>, ClientError> {
+        self.outbound_stats("/stats/outbound/clicks/location", filter)
+            .await
+    }
+
+    async fn outbound_stats<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        filter: &StatsFilter,
+    ) -> Result<T, ClientError> {
+        let request = self.http_client.get(self.endpoint(path)?).query(filter);
+
+        self.execute(request).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_missing_daily_counts_default_to_zero() {
+        let stats: StatsSeries<BounceCounts> = serde_json::from_value(json!({
+            "Days": [
+                { "Date": "2014-01-01", "HardBounce": 12, "SoftBounce": 36 },
+                { "Date": "2014-01-04", "SMTPApiError": 2, "Transient": 7 }
+            ],
+            "HardBounce": 12,
+            "SMTPApiError": 2,
+            "SoftBounce": 36,
+            "Transient": 7
+        }))
+        .unwrap();
+
+        assert_eq!(stats.days.len(), 2);
+        assert_eq!(stats.days[0].counts.hard_bounce, 12);
+        assert_eq!(stats.days[0].counts.transient, 0);
+        assert_eq!(stats.days[1].date, "2014-01-04");
+        assert_eq!(stats.totals.smtp_api_error, 2);
+    }
+
+    #[test]
+    fn test_usage_counts_keep_reported_names() {
+        let stats: StatsSeries<UsageCounts> = serde_json::from_value(json!({
+            "Days": [
+                { "Date": "2014-01-01", "Google Chrome": 1, "Safari": 1 }
+            ],
+            "Google Chrome": 4,
+            "Safari": 2
+        }))
+        .unwrap();
+
+        assert_eq!(stats.days[0].counts.get("Safari"), Some(&1));
+        assert_eq!(stats.totals.get("Google Chrome"), Some(&4));
+        assert!(!stats.totals.contains_key("Days"));
+    }
+
+    #[test]
+    fn test_stats_filter_serialization() {
+        let filter = StatsFilter::new()
+            .tag("welcome")
+            .from_date("2024-01-01")
+            .message_stream("outbound");
+
+        let value = serde_json::to_value(&filter).unwrap();
+
+        assert_eq!(
+            value,
+            json!({
+                "tag": "welcome",
+                "fromdate": "2024-01-01",
+                "messagestream": "outbound"
+            })
+        );
+    }
+}
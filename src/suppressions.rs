@@ -0,0 +1,263 @@
+use crate::error::ClientError;
+use crate::{path_segment, Client, Email};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SuppressionReason {
+    HardBounce,
+    SpamComplaint,
+    ManualSuppression,
+    /// A reason this client does not know about yet. It can't be used as a
+    /// filter.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+/// Who suppressed the address.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SuppressionOrigin {
+    Recipient,
+    Customer,
+    Admin,
+    /// A origin this client does not know about yet. It can't be used as a
+    /// filter.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Suppression {
+    pub email_address: String,
+    pub suppression_reason: SuppressionReason,
+    pub origin: SuppressionOrigin,
+    pub created_at: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SuppressionList {
+    pub suppressions: Vec<Suppression>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SuppressionStatus {
+    Suppressed,
+    Deleted,
+    Failed,
+    /// A status this client does not know about yet.
+    #[serde(other)]
+    Other,
+}
+
+/// The outcome of suppressing or reactivating a single address.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SuppressionResult {
+    pub email_address: String,
+    pub status: SuppressionStatus,
+    /// Why the change failed.
+    pub message: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SuppressionResults {
+    pub suppressions: Vec<SuppressionResult>,
+}
+
+impl SuppressionResults {
+    /// The addresses that could not be changed.
+    pub fn failed(&self) -> impl Iterator<Item = &SuppressionResult> {
+        self.suppressions
+            .iter()
+            .filter(|result| result.status == SuppressionStatus::Failed)
+    }
+}
+
+/// Query parameters for `GET /message-streams/{stream}/suppressions/dump`.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct SuppressionFilter {
+    #[serde(rename = "SuppressionReason", skip_serializing_if = "Option::is_none")]
+    suppression_reason: Option<SuppressionReason>,
+    #[serde(rename = "Origin", skip_serializing_if = "Option::is_none")]
+    origin: Option<SuppressionOrigin>,
+    #[serde(rename = "EmailAddress", skip_serializing_if = "Option::is_none")]
+    email_address: Option<String>,
+    #[serde(rename = "fromdate", skip_serializing_if = "Option::is_none")]
+    from_date: Option<String>,
+    #[serde(rename = "todate", skip_serializing_if = "Option::is_none")]
+    to_date: Option<String>,
+}
+
+impl SuppressionFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn suppression_reason(mut self, suppression_reason: SuppressionReason) -> Self {
+        self.suppression_reason = Some(suppression_reason);
+        self
+    }
+
+    pub fn origin(mut self, origin: SuppressionOrigin) -> Self {
+        self.origin = Some(origin);
+        self
+    }
+
+    pub fn email_address(mut self, email: &Email) -> Self {
+        self.email_address = Some(email.as_ref().to_string());
+        self
+    }
+
+    /// Only suppressions from this date on, e.g. `2024-01-31`.
+    pub fn from_date(mut self, from_date: impl Into<String>) -> Self {
+        self.from_date = Some(from_date.into());
+        self
+    }
+
+    /// Only suppressions up to this date, e.g. `2024-02-29`.
+    pub fn to_date(mut self, to_date: impl Into<String>) -> Self {
+        self.to_date = Some(to_date.into());
+        self
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct SuppressionRequest<'a> {
+    suppressions: Vec<SuppressionEntry<'a>>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct SuppressionEntry<'a> {
+    email_address: &'a Email,
+}
+
+impl<'a> SuppressionRequest<'a> {
+    fn new(emails: &'a [Email]) -> Self {
+        Self {
+            suppressions: emails
+                .iter()
+                .map(|email_address| SuppressionEntry { email_address })
+                .collect(),
+        }
+    }
+}
+
+impl Client {
+    #[tracing::instrument(name = "List suppressions using postmark client", skip(self))]
+    pub async fn list_suppressions(
+        &self,
+        message_stream: &str,
+        filter: &SuppressionFilter,
+    ) -> Result<SuppressionList, ClientError> {
+        let message_stream = path_segment(message_stream)?;
+        let request = self
+            .http_client
+            .get(self.endpoint(&format!(
+                "/message-streams/{message_stream}/suppressions/dump"
+            ))?)
+            .query(filter);
+
+        self.execute(request).await
+    }
+
+    /// Stops sending to `emails` on the message stream.
+    #[tracing::instrument(name = "Create suppressions using postmark client", skip(self, emails))]
+    pub async fn create_suppressions(
+        &self,
+        message_stream: &str,
+        emails: &[Email],
+    ) -> Result<SuppressionResults, ClientError> {
+        let message_stream = path_segment(message_stream)?;
+        let request = self
+            .http_client
+            .post(self.endpoint(&format!("/message-streams/{message_stream}/suppressions"))?)
+            .json(&SuppressionRequest::new(emails));
+
+        self.execute(request).await
+    }
+
+    /// Reactivates `emails` on the message stream. Suppressions caused by
+    /// spam complaints can't be deleted.
+    #[tracing::instrument(name = "Delete suppressions using postmark client", skip(self, emails))]
+    pub async fn delete_suppressions(
+        &self,
+        message_stream: &str,
+        emails: &[Email],
+    ) -> Result<SuppressionResults, ClientError> {
+        let message_stream = path_segment(message_stream)?;
+        let request = self
+            .http_client
+            .post(self.endpoint(&format!(
+                "/message-streams/{message_stream}/suppressions/delete"
+            ))?)
+            .json(&SuppressionRequest::new(emails));
+
+        self.execute(request).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_suppression_request_serialization() {
+        let emails = vec![
+            Email::parse("good.address@wildbit.com").unwrap(),
+            Email::parse("spammy.address@wildbit.com").unwrap(),
+        ];
+
+        let value = serde_json::to_value(SuppressionRequest::new(&emails)).unwrap();
+
+        assert_eq!(
+            value,
+            json!({
+                "Suppressions": [
+                    { "EmailAddress": "good.address@wildbit.com" },
+                    { "EmailAddress": "spammy.address@wildbit.com" }
+                ]
+            })
+        );
+    }
+
+    #[test]
+    fn test_suppression_results_report_failures() {
+        let results: SuppressionResults = serde_json::from_value(json!({
+            "Suppressions": [
+                { "EmailAddress": "good.address@wildbit.com", "Status": "Suppressed", "Message": null },
+                {
+                    "EmailAddress": "spammy.address@wildbit.com",
+                    "Status": "Failed",
+                    "Message": "You do not have the required authority to change this suppression."
+                }
+            ]
+        }))
+        .unwrap();
+
+        let failed: Vec<_> = results.failed().collect();
+
+        assert_eq!(failed.len(), 1);
+        assert_eq!(failed[0].email_address, "spammy.address@wildbit.com");
+    }
+
+    #[test]
+    fn test_unknown_suppression_values_are_read() {
+        let suppression: Suppression = serde_json::from_value(json!({
+            "EmailAddress": "jane@example.com",
+            "SuppressionReason": "InvalidAddress",
+            "Origin": "System",
+            "CreatedAt": "2019-12-10T08:58:33-05:00"
+        }))
+        .unwrap();
+
+        assert_eq!(suppression.suppression_reason, SuppressionReason::Other);
+        assert_eq!(suppression.origin, SuppressionOrigin::Other);
+        let filter = SuppressionFilter::new().origin(suppression.origin);
+        assert!(serde_json::to_value(&filter).is_err());
+    }
+}
@@ -0,0 +1,477 @@
+use crate::error::ClientError;
+use crate::{
+    fetch_all_pages, Client, Template, TemplateBody, TemplateFilter, TemplateSummary, TemplateType,
+};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+const LAYOUTS_DIR: &str = "layouts";
+const META_FILE: &str = "meta.json";
+
+/// A set of templates read from a local directory, to be synced to the
+/// server with [`Client::sync_templates`].
+///
+/// The directory holds one folder per template alias. Each folder contains
+/// the `subject`, `html` and `text` files of the template (a template needs
+/// a `subject` and at least one of `html` or `text`, a layout has no
+/// `subject`) and an optional `meta.json`:
+///
+/// ```text
+/// templates/
+///   welcome/
+///     meta.json   {"name": "Welcome", "layout": "base"}
+///     subject
+///     html
+///     text
+///   layouts/
+///     base/
+///       meta.json {"name": "Base layout"}
+///       html
+///       text
+/// ```
+///
+/// Layouts live in the `layouts` folder, which is therefore not a valid
+/// template alias.
+#[derive(Debug, Clone)]
+pub struct TemplateSync {
+    templates: Vec<LocalTemplate>,
+    dry_run: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct LocalTemplate {
+    alias: String,
+    name: String,
+    template_type: TemplateType,
+    subject: Option<String>,
+    html_body: Option<String>,
+    text_body: Option<String>,
+    layout_template: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct LocalTemplateMeta {
+    name: Option<String>,
+    layout: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TemplateSyncAction {
+    Create,
+    Edit,
+    Delete,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TemplateSyncChange {
+    pub action: TemplateSyncAction,
+    pub alias: String,
+    pub template_type: TemplateType,
+}
+
+/// A reason the server templates can't be made to match the local ones.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TemplateSyncConflict {
+    /// The local and server templates with this alias differ in type, which
+    /// an edit can't change.
+    TypeMismatch {
+        alias: String,
+        local: TemplateType,
+        server: TemplateType,
+    },
+    /// A server layout missing locally would be deleted while a template
+    /// that is kept still uses it.
+    LayoutInUse { layout: String, template: String },
+}
+
+impl fmt::Display for TemplateSyncConflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TypeMismatch {
+                alias,
+                local,
+                server,
+            } => write!(
+                f,
+                "template '{alias}' is a {local:?} locally but a {server:?} on the server"
+            ),
+            Self::LayoutInUse { layout, template } => write!(
+                f,
+                "layout '{layout}' can't be deleted as template '{template}' uses it"
+            ),
+        }
+    }
+}
+
+/// The changes a sync made, or would make when run as a dry run. A dry run
+/// also lists the conflicts that would make the sync fail.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TemplateSyncReport {
+    pub dry_run: bool,
+    pub changes: Vec<TemplateSyncChange>,
+    pub conflicts: Vec<TemplateSyncConflict>,
+}
+
+impl TemplateSyncReport {
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty() && self.conflicts.is_empty()
+    }
+}
+
+impl TemplateSync {
+    pub fn from_dir(dir: impl AsRef<Path>) -> Result<Self, ClientError> {
+        let dir = dir.as_ref();
+        let mut templates = read_templates(dir, TemplateType::Standard)?;
+
+        let layouts_dir = dir.join(LAYOUTS_DIR);
+        if layouts_dir.is_dir() {
+            templates.extend(read_templates(&layouts_dir, TemplateType::Layout)?);
+        }
+
+        Ok(Self {
+            templates,
+            dry_run: false,
+        })
+    }
+
+    /// Only report the changes, without applying them.
+    pub fn dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+}
+
+fn read_templates(
+    dir: &Path,
+    template_type: TemplateType,
+) -> Result<Vec<LocalTemplate>, ClientError> {
+    let mut templates = Vec::new();
+
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if !path.is_dir() {
+            continue;
+        }
+        let Some(alias) = path.file_name().and_then(|name| name.to_str()) else {
+            continue;
+        };
+        if template_type == TemplateType::Standard && alias == LAYOUTS_DIR {
+            continue;
+        }
+
+        let meta = match read_optional(&path.join(META_FILE))? {
+            Some(meta) => serde_json::from_str(&meta)?,
+            None => LocalTemplateMeta::default(),
+        };
+        let template = LocalTemplate {
+            alias: alias.to_string(),
+            name: meta.name.unwrap_or_else(|| alias.to_string()),
+            template_type,
+            subject: read_optional(&path.join("subject"))?,
+            html_body: read_optional(&path.join("html"))?,
+            text_body: read_optional(&path.join("text"))?,
+            layout_template: meta.layout,
+        };
+
+        if template.html_body.is_none() && template.text_body.is_none() {
+            return Err(ClientError::Configuration(format!(
+                "template '{}' needs an html or text file",
+                template.alias
+            )));
+        }
+        match template_type {
+            TemplateType::Standard if template.subject.is_none() => {
+                return Err(ClientError::Configuration(format!(
+                    "template '{}' needs a subject file",
+                    template.alias
+                )));
+            }
+            TemplateType::Layout
+                if template.subject.is_some() || template.layout_template.is_some() =>
+            {
+                return Err(ClientError::Configuration(format!(
+                    "layout '{}' cannot have a subject or a layout",
+                    template.alias
+                )));
+            }
+            _ => {}
+        }
+        templates.push(template);
+    }
+
+    templates.sort_by(|a, b| a.alias.cmp(&b.alias));
+    Ok(templates)
+}
+
+fn read_optional(path: &Path) -> Result<Option<String>, ClientError> {
+    if path.is_file() {
+        Ok(Some(fs::read_to_string(path)?))
+    } else {
+        Ok(None)
+    }
+}
+
+impl LocalTemplate {
+    // The server returns parts that were cleared as either null or empty,
+    // so both count as missing.
+    fn matches(&self, template: &Template) -> bool {
+        fn part(value: &Option<String>) -> &str {
+            value.as_deref().unwrap_or_default()
+        }
+
+        self.name == template.name
+            && part(&self.subject) == part(&template.subject)
+            && part(&self.html_body) == part(&template.html_body)
+            && part(&self.text_body) == part(&template.text_body)
+            && part(&self.layout_template) == part(&template.layout_template)
+    }
+
+    fn body(&self, create: bool) -> TemplateBody {
+        let mut builder = TemplateBody::builder().name(&self.name);
+
+        if create {
+            builder = builder.alias(&self.alias).template_type(self.template_type);
+        }
+        if let Some(subject) = &self.subject {
+            builder = builder.subject(subject);
+        }
+        // Layouts can't use a layout. For templates, an empty one removes
+        // the layout a server template had.
+        if self.template_type == TemplateType::Standard {
+            builder = builder.layout_template(self.layout_template.clone().unwrap_or_default());
+        }
+        // An empty part clears the one a server template had, which only
+        // matters when editing.
+        match (&self.html_body, create) {
+            (Some(html_body), _) => builder = builder.html_body(html_body),
+            (None, false) => builder = builder.html_body(""),
+            (None, true) => {}
+        }
+        match (&self.text_body, create) {
+            (Some(text_body), _) => builder = builder.text_body(text_body),
+            (None, false) => builder = builder.text_body(""),
+            (None, true) => {}
+        }
+
+        builder.build()
+    }
+}
+
+impl Client {
+    /// Creates, edits and deletes server templates so they match `sync`.
+    /// Server templates without an alias are left untouched.
+    ///
+    /// Nothing is changed when the sync has conflicts, which are returned as
+    /// a [`ClientError::Configuration`] error, or listed in the report of a
+    /// dry run.
+    #[tracing::instrument(name = "Sync templates using postmark client", skip(self, sync))]
+    pub async fn sync_templates(
+        &self,
+        sync: &TemplateSync,
+    ) -> Result<TemplateSyncReport, ClientError> {
+        let (remote, unaliased) = self.fetch_server_templates().await?;
+
+        let mut changes = Vec::new();
+        let mut conflicts = Vec::new();
+        for local in &sync.templates {
+            match remote.get(&local.alias) {
+                None => changes.push((TemplateSyncAction::Create, local.template_type, local)),
+                Some(template) if template.template_type != local.template_type => {
+                    conflicts.push(TemplateSyncConflict::TypeMismatch {
+                        alias: local.alias.clone(),
+                        local: local.template_type,
+                        server: template.template_type,
+                    })
+                }
+                Some(template) if !local.matches(template) => {
+                    changes.push((TemplateSyncAction::Edit, local.template_type, local))
+                }
+                Some(_) => {}
+            }
+        }
+        let mut deletions: Vec<_> = remote
+            .iter()
+            .filter(|(alias, _)| !sync.templates.iter().any(|local| &local.alias == *alias))
+            .collect();
+
+        // Kept templates end up with their local layout, while templates
+        // without an alias keep the one they have on the server.
+        let layout_users = sync
+            .templates
+            .iter()
+            .map(|local| (&local.alias, &local.layout_template))
+            .chain(
+                unaliased
+                    .iter()
+                    .map(|summary| (&summary.name, &summary.layout_template)),
+            );
+        for (template, layout) in layout_users {
+            let deleted = deletions.iter().find(|(alias, deleted)| {
+                deleted.template_type == TemplateType::Layout && layout.as_ref() == Some(*alias)
+            });
+            if let Some((layout, _)) = deleted {
+                conflicts.push(TemplateSyncConflict::LayoutInUse {
+                    layout: layout.to_string(),
+                    template: template.clone(),
+                });
+            }
+        }
+
+        if !sync.dry_run && !conflicts.is_empty() {
+            let conflicts: Vec<_> = conflicts.iter().map(ToString::to_string).collect();
+            return Err(ClientError::Configuration(conflicts.join(", ")));
+        }
+
+        // Layouts must exist before the templates that use them, and can
+        // only be deleted once nothing uses them anymore.
+        changes.sort_by_key(|(_, template_type, _)| *template_type != TemplateType::Layout);
+        deletions.sort_by_key(|(_, template)| template.template_type == TemplateType::Layout);
+
+        let mut report = TemplateSyncReport {
+            dry_run: sync.dry_run,
+            changes: Vec::new(),
+            conflicts,
+        };
+
+        for (action, template_type, local) in changes {
+            if !sync.dry_run {
+                match action {
+                    TemplateSyncAction::Create => {
+                        self.create_template(&local.body(true)).await?;
+                    }
+                    _ => {
+                        self.edit_template(local.alias.as_str(), &local.body(false))
+                            .await?;
+                    }
+                }
+            }
+            report.changes.push(TemplateSyncChange {
+                action,
+                alias: local.alias.clone(),
+                template_type,
+            });
+        }
+
+        for (alias, template) in deletions {
+            if !sync.dry_run {
+                self.delete_template(template.template_id).await?;
+            }
+            report.changes.push(TemplateSyncChange {
+                action: TemplateSyncAction::Delete,
+                alias: alias.clone(),
+                template_type: template.template_type,
+            });
+        }
+
+        Ok(report)
+    }
+
+    /// Fetches the server templates with an alias, along with the summaries
+    /// of those without one.
+    async fn fetch_server_templates(
+        &self,
+    ) -> Result<(BTreeMap<String, Template>, Vec<TemplateSummary>), ClientError> {
+        let summaries = fetch_all_pages(|offset| async move {
+            let page = self
+                .list_templates(&TemplateFilter::new().offset(offset))
+                .await?;
+            Ok((page.templates, page.total_count))
+        })
+        .await?;
+
+        let mut templates = BTreeMap::new();
+        let mut unaliased = Vec::new();
+        for summary in summaries {
+            match &summary.alias {
+                Some(alias) => {
+                    let template = self.get_template(summary.template_id).await?;
+                    templates.insert(alias.clone(), template);
+                }
+                None => unaliased.push(summary),
+            }
+        }
+
+        Ok((templates, unaliased))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_from_dir_reads_templates_and_layouts() {
+        let dir = TempDir::new().unwrap();
+        let welcome = dir.path().join("welcome");
+        let base = dir.path().join(LAYOUTS_DIR).join("base");
+        fs::create_dir_all(&welcome).unwrap();
+        fs::create_dir_all(&base).unwrap();
+        fs::write(
+            welcome.join(META_FILE),
+            r#"{"name": "Welcome", "layout": "base"}"#,
+        )
+        .unwrap();
+        fs::write(welcome.join("subject"), "Hi {{name}}").unwrap();
+        fs::write(welcome.join("html"), "<p>Hi {{name}}</p>").unwrap();
+        fs::write(base.join("html"), "{{{@content}}}").unwrap();
+
+        let sync = TemplateSync::from_dir(dir.path()).unwrap();
+
+        assert_eq!(sync.templates.len(), 2);
+        let welcome = &sync.templates[0];
+        assert_eq!(welcome.alias, "welcome");
+        assert_eq!(welcome.name, "Welcome");
+        assert_eq!(welcome.layout_template.as_deref(), Some("base"));
+        assert_eq!(welcome.text_body, None);
+        let base = &sync.templates[1];
+        assert_eq!(base.alias, "base");
+        assert_eq!(base.name, "base");
+        assert_eq!(base.template_type, TemplateType::Layout);
+    }
+
+    #[test]
+    fn test_from_dir_rejects_a_template_without_subject() {
+        let dir = TempDir::new().unwrap();
+        fs::create_dir_all(dir.path().join("welcome")).unwrap();
+        fs::write(dir.path().join("welcome").join("html"), "<p>Hi</p>").unwrap();
+
+        let result = TemplateSync::from_dir(dir.path());
+
+        assert!(matches!(result, Err(ClientError::Configuration(_))));
+    }
+
+    #[test]
+    fn test_removed_part_is_cleared_and_then_matches() {
+        let local = LocalTemplate {
+            alias: "welcome".to_string(),
+            name: "Welcome".to_string(),
+            template_type: TemplateType::Standard,
+            subject: Some("Hi".to_string()),
+            html_body: Some("<p>Hi</p>".to_string()),
+            text_body: None,
+            layout_template: None,
+        };
+
+        let body = serde_json::to_value(local.body(false)).unwrap();
+        assert_eq!(body["TextBody"], "");
+        assert_eq!(body["LayoutTemplate"], "");
+
+        let cleared = Template {
+            template_id: 1,
+            name: "Welcome".to_string(),
+            alias: Some("welcome".to_string()),
+            subject: Some("Hi".to_string()),
+            html_body: Some("<p>Hi</p>".to_string()),
+            text_body: Some(String::new()),
+            associated_server_id: 1,
+            active: true,
+            template_type: TemplateType::Standard,
+            layout_template: None,
+        };
+        assert!(local.matches(&cleared));
+    }
+}
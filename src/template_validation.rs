@@ -0,0 +1,259 @@
+use crate::error::ClientError;
+use crate::{Client, TemplateBody, TemplateType};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeSet;
+
+/// The result of `POST /templates/validate`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct TemplateValidation {
+    pub all_content_is_valid: bool,
+    pub subject: Option<TemplateValidationPart>,
+    pub html_body: Option<TemplateValidationPart>,
+    pub text_body: Option<TemplateValidationPart>,
+    #[serde(default)]
+    pub suggested_template_model: Value,
+}
+
+/// The validation outcome of a single template part (subject, html or text).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct TemplateValidationPart {
+    pub content_is_valid: bool,
+    #[serde(default)]
+    pub validation_errors: Vec<TemplateRenderError>,
+    pub rendered_content: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct TemplateRenderError {
+    pub message: String,
+    pub line: u32,
+    pub character_position: u32,
+}
+
+/// Keys that differ between a model and a template's suggested model.
+///
+/// Nested keys are reported as dotted paths, with `[]` marking the elements
+/// of a list, e.g. `order.items[].name`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ModelShapeReport {
+    /// Keys the template uses that the model does not provide.
+    pub missing: Vec<String>,
+    /// Keys the model provides that the template never uses.
+    pub extra: Vec<String>,
+}
+
+impl ModelShapeReport {
+    pub fn is_match(&self) -> bool {
+        self.missing.is_empty() && self.extra.is_empty()
+    }
+}
+
+impl TemplateValidation {
+    pub fn is_valid(&self) -> bool {
+        self.all_content_is_valid
+    }
+
+    /// Compares `model` against the `SuggestedTemplateModel` returned by
+    /// Postmark.
+    pub fn compare_model<M: Serialize>(&self, model: &M) -> Result<ModelShapeReport, ClientError> {
+        let model = serde_json::to_value(model)?;
+        Ok(compare_model_shape(&self.suggested_template_model, &model))
+    }
+}
+
+/// Compares the keys of `model` against those of `suggested`.
+pub fn compare_model_shape(suggested: &Value, model: &Value) -> ModelShapeReport {
+    let mut missing = BTreeSet::new();
+    let mut extra = BTreeSet::new();
+    walk_shape(suggested, model, "", &mut missing, &mut extra);
+
+    ModelShapeReport {
+        missing: missing.into_iter().collect(),
+        extra: extra.into_iter().collect(),
+    }
+}
+
+fn walk_shape(
+    suggested: &Value,
+    model: &Value,
+    prefix: &str,
+    missing: &mut BTreeSet<String>,
+    extra: &mut BTreeSet<String>,
+) {
+    match (suggested, model) {
+        (Value::Object(suggested), Value::Object(model)) => {
+            for (key, suggested_value) in suggested {
+                let path = join_path(prefix, key);
+                match model.get(key) {
+                    Some(model_value) => {
+                        walk_shape(suggested_value, model_value, &path, missing, extra)
+                    }
+                    None => {
+                        missing.insert(path);
+                    }
+                }
+            }
+            for key in model.keys().filter(|key| !suggested.contains_key(*key)) {
+                extra.insert(join_path(prefix, key));
+            }
+        }
+        (Value::Array(suggested), Value::Array(model)) => {
+            // Postmark suggests a single element to describe every item.
+            if let Some(suggested) = suggested.first() {
+                let path = format!("{prefix}[]");
+                for item in model {
+                    walk_shape(suggested, item, &path, missing, extra);
+                }
+            }
+        }
+        // A scalar placeholder accepts any value, and a structural mismatch
+        // is left for the renderer to report.
+        _ => {}
+    }
+}
+
+fn join_path(prefix: &str, key: &str) -> String {
+    if prefix.is_empty() {
+        key.to_string()
+    } else {
+        format!("{prefix}.{key}")
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct ValidateTemplateRequest<'a, M> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    subject: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    html_body: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    text_body: Option<&'a str>,
+    test_render_model: &'a M,
+    inline_css_for_html_test_render: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    template_type: Option<TemplateType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    layout_template: Option<&'a str>,
+}
+
+impl Client {
+    /// Validates template content without saving it, rendering it with
+    /// `test_render_model`.
+    #[tracing::instrument(
+        name = "Validate template using postmark client",
+        skip(self, body, test_render_model)
+    )]
+    pub async fn validate_template<M: Serialize>(
+        &self,
+        body: &TemplateBody,
+        test_render_model: &M,
+    ) -> Result<TemplateValidation, ClientError> {
+        let body = ValidateTemplateRequest {
+            subject: body.subject.as_deref(),
+            html_body: body.html_body.as_deref(),
+            text_body: body.text_body.as_deref(),
+            test_render_model,
+            inline_css_for_html_test_render: true,
+            template_type: body.template_type,
+            layout_template: body.layout_template.as_deref(),
+        };
+        let request = self
+            .http_client
+            .post(self.endpoint("/templates/validate")?)
+            .json(&body);
+
+        self.execute(request).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_validation_deserialization() {
+        let validation: TemplateValidation = serde_json::from_value(json!({
+            "AllContentIsValid": false,
+            "HtmlBody": {
+                "ContentIsValid": false,
+                "ValidationErrors": [{
+                    "Message": "The syntax for this template is invalid.",
+                    "Line": 1,
+                    "CharacterPosition": 12
+                }],
+                "RenderedContent": null
+            },
+            "TextBody": {
+                "ContentIsValid": true,
+                "ValidationErrors": [],
+                "RenderedContent": "Hello Jane"
+            },
+            "Subject": {
+                "ContentIsValid": true,
+                "ValidationErrors": [],
+                "RenderedContent": "Welcome"
+            },
+            "SuggestedTemplateModel": { "name": "name_Value" }
+        }))
+        .unwrap();
+
+        assert!(!validation.is_valid());
+        let html = validation.html_body.unwrap();
+        assert_eq!(html.validation_errors[0].line, 1);
+        assert_eq!(html.validation_errors[0].character_position, 12);
+        assert_eq!(
+            validation.text_body.unwrap().rendered_content.as_deref(),
+            Some("Hello Jane")
+        );
+    }
+
+    #[test]
+    fn test_compare_model_shape_reports_nested_differences() {
+        let suggested = json!({
+            "name": "name_Value",
+            "company": { "name": "name_Value", "address": "address_Value" },
+            "items": [{ "title": "title_Value" }]
+        });
+        let model = json!({
+            "name": "Jane",
+            "company": { "name": "Acme" },
+            "items": [{ "title": "Book", "price": 10 }],
+            "unused": true
+        });
+
+        let report = compare_model_shape(&suggested, &model);
+
+        assert_eq!(report.missing, vec!["company.address"]);
+        assert_eq!(report.extra, vec!["items[].price", "unused"]);
+        assert!(!report.is_match());
+    }
+
+    #[test]
+    fn test_compare_model_accepts_matching_struct() {
+        #[derive(Serialize)]
+        struct Model {
+            name: String,
+        }
+
+        let validation = TemplateValidation {
+            all_content_is_valid: true,
+            subject: None,
+            html_body: None,
+            text_body: None,
+            suggested_template_model: json!({ "name": "name_Value" }),
+        };
+        let report = validation
+            .compare_model(&Model {
+                name: "Jane".to_string(),
+            })
+            .unwrap();
+
+        assert!(report.is_match());
+    }
+}
@@ -0,0 +1,306 @@
+use crate::attachment::Attachment;
+use crate::{Email, TrackLink};
+use serde::Serialize;
+use serde_json::Value;
+use std::fmt;
+use std::marker::PhantomData;
+
+/// Identifies a Postmark template either by its numeric id or by its alias.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub enum TemplateRef {
+    #[serde(rename = "TemplateId")]
+    Id(i64),
+    #[serde(rename = "TemplateAlias")]
+    Alias(String),
+}
+
+impl From<i64> for TemplateRef {
+    fn from(id: i64) -> Self {
+        TemplateRef::Id(id)
+    }
+}
+
+impl From<&str> for TemplateRef {
+    fn from(alias: &str) -> Self {
+        TemplateRef::Alias(alias.to_string())
+    }
+}
+
+impl From<String> for TemplateRef {
+    fn from(alias: String) -> Self {
+        TemplateRef::Alias(alias)
+    }
+}
+
+/// A template alias bound to the model type it renders.
+///
+/// Declaring templates this way lets the compiler reject a model that
+/// belongs to a different template:
+///
+/// ```
+/// use postmark_client::{Email, TemplatedEmailBody, TypedTemplate};
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct WelcomeModel {
+///     name: String,
+/// }
+///
+/// const WELCOME: TypedTemplate<WelcomeModel> = TypedTemplate::new("welcome");
+///
+/// let to = Email::parse("jane@example.com").unwrap();
+/// let model = WelcomeModel { name: "Jane".to_string() };
+/// let body = TemplatedEmailBody::typed(to, &WELCOME, model).build();
+/// ```
+///
+/// A typed template can only be sent through [`TemplatedEmailBody::typed`],
+/// so sending it with another model doesn't compile:
+///
+/// ```compile_fail
+/// # use postmark_client::{Email, TemplatedEmailBody, TypedTemplate};
+/// # use serde::Serialize;
+/// # #[derive(Serialize)]
+/// # struct WelcomeModel {
+/// #     name: String,
+/// # }
+/// #[derive(Serialize)]
+/// struct ResetModel {
+///     reset_url: String,
+/// }
+///
+/// const WELCOME: TypedTemplate<WelcomeModel> = TypedTemplate::new("welcome");
+///
+/// let to = Email::parse("jane@example.com").unwrap();
+/// let model = ResetModel { reset_url: "https://example.com/reset".to_string() };
+/// let body = TemplatedEmailBody::typed(to, &WELCOME, model).build();
+/// ```
+pub struct TypedTemplate<M> {
+    alias: &'static str,
+    model: PhantomData<fn(M)>,
+}
+
+impl<M> TypedTemplate<M> {
+    pub const fn new(alias: &'static str) -> Self {
+        Self {
+            alias,
+            model: PhantomData,
+        }
+    }
+
+    // Not public, so the alias can't be passed to the untyped builder with
+    // another model.
+    pub(crate) fn alias(&self) -> &'static str {
+        self.alias
+    }
+}
+
+impl<M> Clone for TypedTemplate<M> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<M> Copy for TypedTemplate<M> {}
+
+impl<M> fmt::Debug for TypedTemplate<M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TypedTemplate")
+            .field("alias", &self.alias)
+            .finish()
+    }
+}
+
+/// An email rendered from a Postmark template. The model defaults to a
+/// loose [`Value`], but any `Serialize` type can be used to have the
+/// compiler check its shape.
+#[derive(Debug)]
+pub struct TemplatedEmailBody<M = Value> {
+    pub(crate) to: Email,
+    pub(crate) template: TemplateRef,
+    pub(crate) template_model: M,
+    pub(crate) inline_css: bool,
+    pub(crate) cc: Option<Vec<Email>>,
+    pub(crate) bcc: Option<Vec<Email>>,
+    pub(crate) tag: Option<String>,
+    pub(crate) message_stream: Option<String>,
+    pub(crate) reply_to: Option<Email>,
+    pub(crate) metadata: Option<Value>,
+    pub(crate) track_opens: bool,
+    pub(crate) track_links: TrackLink,
+    pub(crate) attachments: Option<Vec<Attachment>>,
+}
+
+impl<M: Serialize> TemplatedEmailBody<M> {
+    pub fn builder(
+        to: Email,
+        template: impl Into<TemplateRef>,
+        template_model: M,
+    ) -> TemplatedEmailBodyBuilder<M> {
+        TemplatedEmailBodyBuilder::new(to, template, template_model)
+    }
+
+    /// Starts a builder for a template whose model type is fixed by `template`.
+    pub fn typed(
+        to: Email,
+        template: &TypedTemplate<M>,
+        template_model: M,
+    ) -> TemplatedEmailBodyBuilder<M> {
+        TemplatedEmailBodyBuilder::new(to, template.alias(), template_model)
+    }
+}
+
+// The builder for TemplatedEmailBody
+pub struct TemplatedEmailBodyBuilder<M = Value> {
+    to: Email,
+    template: TemplateRef,
+    template_model: M,
+    inline_css: bool,
+    cc: Option<Vec<Email>>,
+    bcc: Option<Vec<Email>>,
+    tag: Option<String>,
+    message_stream: Option<String>,
+    reply_to: Option<Email>,
+    metadata: Option<Value>,
+    track_opens: bool,
+    track_links: TrackLink,
+    attachments: Option<Vec<Attachment>>,
+}
+
+impl<M: Serialize> TemplatedEmailBodyBuilder<M> {
+    pub fn new(to: Email, template: impl Into<TemplateRef>, template_model: M) -> Self {
+        Self {
+            to,
+            template: template.into(),
+            template_model,
+            inline_css: true,
+            cc: None,
+            bcc: None,
+            tag: None,
+            message_stream: None,
+            reply_to: None,
+            metadata: None,
+            track_opens: true,
+            track_links: TrackLink::HtmlAndText,
+            attachments: None,
+        }
+    }
+
+    pub fn inline_css(mut self, inline_css: bool) -> Self {
+        self.inline_css = inline_css;
+        self
+    }
+
+    pub fn cc(mut self, cc: Vec<Email>) -> Self {
+        self.cc = Some(cc);
+        self
+    }
+
+    pub fn bcc(mut self, bcc: Vec<Email>) -> Self {
+        self.bcc = Some(bcc);
+        self
+    }
+
+    pub fn tag(mut self, tag: impl Into<String>) -> Self {
+        self.tag = Some(tag.into());
+        self
+    }
+
+    /// The message stream to send through. Postmark uses the default
+    /// transactional stream when none is set.
+    pub fn message_stream(mut self, message_stream: impl Into<String>) -> Self {
+        self.message_stream = Some(message_stream.into());
+        self
+    }
+
+    pub fn reply_to(mut self, reply_to: Email) -> Self {
+        self.reply_to = Some(reply_to);
+        self
+    }
+
+    pub fn metadata(mut self, metadata: Value) -> Self {
+        self.metadata = Some(metadata);
+        self
+    }
+
+    pub fn track_opens(mut self, track_opens: bool) -> Self {
+        self.track_opens = track_opens;
+        self
+    }
+
+    pub fn track_links(mut self, track_links: TrackLink) -> Self {
+        self.track_links = track_links;
+        self
+    }
+
+    pub fn attachments(mut self, attachments: Vec<Attachment>) -> Self {
+        self.attachments = Some(attachments);
+        self
+    }
+
+    pub fn build(self) -> TemplatedEmailBody<M> {
+        TemplatedEmailBody {
+            to: self.to,
+            template: self.template,
+            template_model: self.template_model,
+            inline_css: self.inline_css,
+            cc: self.cc,
+            bcc: self.bcc,
+            tag: self.tag,
+            message_stream: self.message_stream,
+            reply_to: self.reply_to,
+            metadata: self.metadata,
+            track_opens: self.track_opens,
+            track_links: self.track_links,
+            attachments: self.attachments,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_templated_email_builder() {
+        let to = Email::parse("to@example.com").unwrap();
+
+        let request = TemplatedEmailBody::builder(to, 1234, json!({ "name": "Jane" }))
+            .inline_css(false)
+            .tag("welcome")
+            .track_links(TrackLink::None)
+            .build();
+
+        assert_eq!(request.to.as_ref(), "to@example.com");
+        assert_eq!(request.template, TemplateRef::Id(1234));
+        assert_eq!(request.template_model["name"], "Jane");
+        assert!(!request.inline_css);
+        assert_eq!(request.tag.unwrap(), "welcome");
+        assert!(matches!(request.track_links, TrackLink::None));
+    }
+
+    #[test]
+    fn test_typed_template_builder() {
+        #[derive(Serialize)]
+        struct ResetModel {
+            reset_url: String,
+        }
+
+        const RESET: TypedTemplate<ResetModel> = TypedTemplate::new("password-reset");
+
+        let to = Email::parse("to@example.com").unwrap();
+        let model = ResetModel {
+            reset_url: "https://example.com/reset".to_string(),
+        };
+        let request = TemplatedEmailBody::typed(to, &RESET, model).build();
+
+        assert_eq!(
+            request.template,
+            TemplateRef::Alias("password-reset".to_string())
+        );
+        assert_eq!(
+            request.template_model.reset_url,
+            "https://example.com/reset"
+        );
+    }
+}
@@ -0,0 +1,409 @@
+use crate::error::ClientError;
+use crate::{path_segment, AccountClient, ApiResponse, Client, TemplateRef};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TemplateType {
+    Standard,
+    Layout,
+}
+
+/// A template as returned by `GET /templates/{idOrAlias}`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Template {
+    pub template_id: i64,
+    pub name: String,
+    pub alias: Option<String>,
+    pub subject: Option<String>,
+    pub html_body: Option<String>,
+    pub text_body: Option<String>,
+    pub associated_server_id: i64,
+    pub active: bool,
+    pub template_type: TemplateType,
+    pub layout_template: Option<String>,
+}
+
+/// The short form of a template returned when listing, creating or editing
+/// templates.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct TemplateSummary {
+    pub template_id: i64,
+    pub name: String,
+    pub alias: Option<String>,
+    pub active: bool,
+    pub template_type: TemplateType,
+    pub layout_template: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct TemplateList {
+    pub total_count: u32,
+    pub templates: Vec<TemplateSummary>,
+}
+
+/// Query parameters for `GET /templates`.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct TemplateFilter {
+    count: u32,
+    offset: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    template_type: Option<TemplateType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    layout_template: Option<String>,
+}
+
+impl Default for TemplateFilter {
+    fn default() -> Self {
+        Self {
+            count: 100,
+            offset: 0,
+            template_type: None,
+            layout_template: None,
+        }
+    }
+}
+
+impl TemplateFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn count(mut self, count: u32) -> Self {
+        self.count = count;
+        self
+    }
+
+    pub fn offset(mut self, offset: u32) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    pub fn template_type(mut self, template_type: TemplateType) -> Self {
+        self.template_type = Some(template_type);
+        self
+    }
+
+    /// Only list templates that use the layout with this alias.
+    pub fn layout_template(mut self, alias: impl Into<String>) -> Self {
+        self.layout_template = Some(alias.into());
+        self
+    }
+}
+
+/// The fields sent when creating or editing a template. Fields left unset
+/// are omitted, so an edit only touches what was explicitly provided.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct TemplateBody {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) alias: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) subject: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) html_body: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) text_body: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) template_type: Option<TemplateType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) layout_template: Option<String>,
+}
+
+impl TemplateBody {
+    pub fn builder() -> TemplateBodyBuilder {
+        TemplateBodyBuilder::new()
+    }
+}
+
+// The builder for TemplateBody
+#[derive(Debug, Clone, Default)]
+pub struct TemplateBodyBuilder {
+    body: TemplateBody,
+}
+
+impl TemplateBodyBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.body.name = Some(name.into());
+        self
+    }
+
+    pub fn alias(mut self, alias: impl Into<String>) -> Self {
+        self.body.alias = Some(alias.into());
+        self
+    }
+
+    pub fn subject(mut self, subject: impl Into<String>) -> Self {
+        self.body.subject = Some(subject.into());
+        self
+    }
+
+    pub fn html_body(mut self, html_body: impl Into<String>) -> Self {
+        self.body.html_body = Some(html_body.into());
+        self
+    }
+
+    pub fn text_body(mut self, text_body: impl Into<String>) -> Self {
+        self.body.text_body = Some(text_body.into());
+        self
+    }
+
+    pub fn template_type(mut self, template_type: TemplateType) -> Self {
+        self.body.template_type = Some(template_type);
+        self
+    }
+
+    pub fn layout_template(mut self, alias: impl Into<String>) -> Self {
+        self.body.layout_template = Some(alias.into());
+        self
+    }
+
+    pub fn build(self) -> TemplateBody {
+        self.body
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TemplatePushAction {
+    Create,
+    Edit,
+}
+
+/// A template that a push creates or edits on the destination server.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct TemplatePushChange {
+    pub action: TemplatePushAction,
+    pub template_id: Option<i64>,
+    pub alias: Option<String>,
+    pub name: String,
+    pub template_type: Option<TemplateType>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct TemplatePushResult {
+    pub total_count: u32,
+    pub templates: Vec<TemplatePushChange>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct TemplatePushRequest {
+    #[serde(rename = "SourceServerID")]
+    source_server_id: i64,
+    #[serde(rename = "DestinationServerID")]
+    destination_server_id: i64,
+    perform_changes: bool,
+}
+
+impl TemplateRef {
+    pub(crate) fn path(&self) -> Result<String, ClientError> {
+        match self {
+            TemplateRef::Id(id) => Ok(format!("/templates/{id}")),
+            TemplateRef::Alias(alias) => Ok(format!("/templates/{}", path_segment(alias)?)),
+        }
+    }
+}
+
+impl Client {
+    #[tracing::instrument(name = "List templates using postmark client", skip(self))]
+    pub async fn list_templates(
+        &self,
+        filter: &TemplateFilter,
+    ) -> Result<TemplateList, ClientError> {
+        let request = self
+            .http_client
+            .get(self.endpoint("/templates")?)
+            .query(filter);
+
+        self.execute(request).await
+    }
+
+    #[tracing::instrument(name = "Get template using postmark client", skip(self, template))]
+    pub async fn get_template(
+        &self,
+        template: impl Into<TemplateRef>,
+    ) -> Result<Template, ClientError> {
+        let request = self
+            .http_client
+            .get(self.endpoint(&template.into().path()?)?);
+
+        self.execute(request).await
+    }
+
+    #[tracing::instrument(name = "Create template using postmark client", skip(self, body))]
+    pub async fn create_template(
+        &self,
+        body: &TemplateBody,
+    ) -> Result<TemplateSummary, ClientError> {
+        let request = self
+            .http_client
+            .post(self.endpoint("/templates")?)
+            .json(body);
+
+        self.execute(request).await
+    }
+
+    #[tracing::instrument(
+        name = "Edit template using postmark client",
+        skip(self, template, body)
+    )]
+    pub async fn edit_template(
+        &self,
+        template: impl Into<TemplateRef>,
+        body: &TemplateBody,
+    ) -> Result<TemplateSummary, ClientError> {
+        let request = self
+            .http_client
+            .put(self.endpoint(&template.into().path()?)?)
+            .json(body);
+
+        self.execute(request).await
+    }
+
+    #[tracing::instrument(name = "Delete template using postmark client", skip(self, template))]
+    pub async fn delete_template(
+        &self,
+        template: impl Into<TemplateRef>,
+    ) -> Result<ApiResponse, ClientError> {
+        let request = self
+            .http_client
+            .delete(self.endpoint(&template.into().path()?)?);
+
+        self.execute(request).await
+    }
+}
+
+impl AccountClient {
+    /// Lists the changes pushing templates from `source_server_id` to
+    /// `destination_server_id` would make, without applying them.
+    #[tracing::instrument(name = "Preview template push using postmark client", skip(self))]
+    pub async fn preview_template_push(
+        &self,
+        source_server_id: i64,
+        destination_server_id: i64,
+    ) -> Result<TemplatePushResult, ClientError> {
+        self.template_push(source_server_id, destination_server_id, false)
+            .await
+    }
+
+    /// Copies templates from `source_server_id` to `destination_server_id`,
+    /// returning the changes that were made.
+    #[tracing::instrument(name = "Push templates using postmark client", skip(self))]
+    pub async fn push_templates(
+        &self,
+        source_server_id: i64,
+        destination_server_id: i64,
+    ) -> Result<TemplatePushResult, ClientError> {
+        self.template_push(source_server_id, destination_server_id, true)
+            .await
+    }
+
+    async fn template_push(
+        &self,
+        source_server_id: i64,
+        destination_server_id: i64,
+        perform_changes: bool,
+    ) -> Result<TemplatePushResult, ClientError> {
+        let body = TemplatePushRequest {
+            source_server_id,
+            destination_server_id,
+            perform_changes,
+        };
+        let request = self
+            .http_client
+            .put(self.endpoint("/templates/push")?)
+            .json(&body);
+
+        self.execute(request).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_template_deserialization() {
+        let template: Template = serde_json::from_value(json!({
+            "Name": "Onboarding Email",
+            "TemplateId": 1234,
+            "Alias": "my-template-alias",
+            "Subject": "Hi there, {{Name}}",
+            "HtmlBody": "Hello dear Postmark user. {{Name}}",
+            "TextBody": "{{Name}} is a {{Occupation}}",
+            "AssociatedServerId": 1,
+            "Active": false,
+            "TemplateType": "Standard",
+            "LayoutTemplate": "my-layout"
+        }))
+        .unwrap();
+
+        assert_eq!(template.template_id, 1234);
+        assert_eq!(template.alias.as_deref(), Some("my-template-alias"));
+        assert_eq!(template.template_type, TemplateType::Standard);
+        assert_eq!(template.layout_template.as_deref(), Some("my-layout"));
+        assert!(!template.active);
+    }
+
+    #[test]
+    fn test_template_body_skips_unset_fields() {
+        let body = TemplateBody::builder().subject("Updated subject").build();
+
+        let value = serde_json::to_value(&body).unwrap();
+
+        assert_eq!(value, json!({ "Subject": "Updated subject" }));
+    }
+
+    #[test]
+    fn test_template_push_result_deserialization() {
+        let result: TemplatePushResult = serde_json::from_value(json!({
+            "TotalCount": 2,
+            "Templates": [
+                {
+                    "Action": "Create",
+                    "TemplateId": null,
+                    "Alias": "comment-notification",
+                    "Name": "Comment notification",
+                    "TemplateType": "Standard"
+                },
+                {
+                    "Action": "Edit",
+                    "TemplateId": 7270,
+                    "Alias": "base",
+                    "Name": "Base layout",
+                    "TemplateType": "Layout"
+                }
+            ]
+        }))
+        .unwrap();
+
+        assert_eq!(result.templates[0].action, TemplatePushAction::Create);
+        assert_eq!(result.templates[1].template_id, Some(7270));
+    }
+
+    #[test]
+    fn test_template_ref_path() {
+        assert_eq!(TemplateRef::Id(42).path().unwrap(), "/templates/42");
+        assert_eq!(
+            TemplateRef::from("welcome").path().unwrap(),
+            "/templates/welcome"
+        );
+        assert_eq!(
+            TemplateRef::from("welcome/../email?x=1").path().unwrap(),
+            "/templates/welcome%2F..%2Femail%3Fx=1"
+        );
+        assert!(TemplateRef::from("..").path().is_err());
+    }
+}
@@ -0,0 +1,351 @@
+use crate::error::ClientError;
+use crate::{ApiResponse, Client};
+use secrecy::{ExposeSecret, SecretString};
+use serde::{Deserialize, Serialize, Serializer};
+
+/// A webhook as returned by `GET /webhooks/{id}`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Webhook {
+    #[serde(rename = "ID")]
+    pub id: i64,
+    #[serde(flatten)]
+    pub config: WebhookConfig,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct WebhookList {
+    pub webhooks: Vec<Webhook>,
+}
+
+/// Where and how Postmark posts the webhooks of a message stream.
+///
+/// The message stream is only used when creating a webhook, since an
+/// existing webhook can't be moved to another stream.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct WebhookConfig {
+    pub url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_stream: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http_auth: Option<HttpAuth>,
+    #[serde(default)]
+    pub http_headers: Vec<HttpHeader>,
+    #[serde(default)]
+    pub triggers: WebhookTriggers,
+}
+
+/// Basic auth credentials Postmark sends with each webhook. Empty
+/// credentials remove the ones of an existing webhook.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct HttpAuth {
+    pub username: String,
+    #[serde(serialize_with = "expose_password")]
+    pub password: SecretString,
+}
+
+impl HttpAuth {
+    pub fn new(username: impl Into<String>, password: SecretString) -> Self {
+        Self {
+            username: username.into(),
+            password,
+        }
+    }
+
+    pub fn empty() -> Self {
+        Self::new("", SecretString::from(""))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.username.is_empty() && self.password.expose_secret().is_empty()
+    }
+}
+
+impl PartialEq for HttpAuth {
+    fn eq(&self, other: &Self) -> bool {
+        self.username == other.username
+            && self.password.expose_secret() == other.password.expose_secret()
+    }
+}
+
+impl Eq for HttpAuth {}
+
+fn expose_password<S: Serializer>(
+    password: &SecretString,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(password.expose_secret())
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct HttpHeader {
+    pub name: String,
+    pub value: String,
+}
+
+/// The events a webhook is posted for. All of them are disabled by default.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct WebhookTriggers {
+    #[serde(default)]
+    pub open: OpenTrigger,
+    #[serde(default)]
+    pub click: WebhookTrigger,
+    #[serde(default)]
+    pub delivery: WebhookTrigger,
+    #[serde(default)]
+    pub bounce: ContentTrigger,
+    #[serde(default)]
+    pub spam_complaint: ContentTrigger,
+    #[serde(default)]
+    pub subscription_change: WebhookTrigger,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct WebhookTrigger {
+    pub enabled: bool,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct OpenTrigger {
+    pub enabled: bool,
+    /// Only post the first open of each recipient.
+    #[serde(default)]
+    pub post_first_open_only: bool,
+}
+
+/// A trigger for bounces or spam complaints, which can include the raw
+/// message Postmark received.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ContentTrigger {
+    pub enabled: bool,
+    #[serde(default)]
+    pub include_content: bool,
+}
+
+impl WebhookTrigger {
+    pub fn enabled() -> Self {
+        Self { enabled: true }
+    }
+}
+
+impl OpenTrigger {
+    pub fn enabled(post_first_open_only: bool) -> Self {
+        Self {
+            enabled: true,
+            post_first_open_only,
+        }
+    }
+}
+
+impl ContentTrigger {
+    pub fn enabled(include_content: bool) -> Self {
+        Self {
+            enabled: true,
+            include_content,
+        }
+    }
+}
+
+impl WebhookConfig {
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            ..Self::default()
+        }
+    }
+
+    pub fn message_stream(mut self, message_stream: impl Into<String>) -> Self {
+        self.message_stream = Some(message_stream.into());
+        self
+    }
+
+    pub fn http_auth(mut self, username: impl Into<String>, password: SecretString) -> Self {
+        self.http_auth = Some(HttpAuth::new(username, password));
+        self
+    }
+
+    /// Removes the basic auth credentials of the webhook when editing it.
+    /// Leaving them unset keeps the current ones.
+    pub fn without_http_auth(mut self) -> Self {
+        self.http_auth = Some(HttpAuth::empty());
+        self
+    }
+
+    pub fn http_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.http_headers.push(HttpHeader {
+            name: name.into(),
+            value: value.into(),
+        });
+        self
+    }
+
+    pub fn triggers(mut self, triggers: WebhookTriggers) -> Self {
+        self.triggers = triggers;
+        self
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct EditWebhookRequest<'a> {
+    url: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    http_auth: Option<&'a HttpAuth>,
+    http_headers: &'a [HttpHeader],
+    triggers: &'a WebhookTriggers,
+}
+
+#[derive(Debug, Serialize)]
+struct WebhookListQuery<'a> {
+    #[serde(rename = "MessageStream", skip_serializing_if = "Option::is_none")]
+    message_stream: Option<&'a str>,
+}
+
+impl Client {
+    /// Lists the webhooks of the server, or only those of `message_stream`.
+    #[tracing::instrument(name = "List webhooks using postmark client", skip(self))]
+    pub async fn list_webhooks(
+        &self,
+        message_stream: Option<&str>,
+    ) -> Result<WebhookList, ClientError> {
+        let request = self
+            .http_client
+            .get(self.endpoint("/webhooks")?)
+            .query(&WebhookListQuery { message_stream });
+
+        self.execute(request).await
+    }
+
+    #[tracing::instrument(name = "Get webhook using postmark client", skip(self))]
+    pub async fn get_webhook(&self, webhook_id: i64) -> Result<Webhook, ClientError> {
+        let request = self
+            .http_client
+            .get(self.endpoint(&format!("/webhooks/{webhook_id}"))?);
+
+        self.execute(request).await
+    }
+
+    #[tracing::instrument(name = "Create webhook using postmark client", skip(self, config))]
+    pub async fn create_webhook(&self, config: &WebhookConfig) -> Result<Webhook, ClientError> {
+        let request = self
+            .http_client
+            .post(self.endpoint("/webhooks")?)
+            .json(config);
+
+        self.execute(request).await
+    }
+
+    /// Replaces the configuration of a webhook. The message stream of
+    /// `config` is ignored, and its credentials are kept unless `config`
+    /// sets new ones or [`WebhookConfig::without_http_auth`].
+    #[tracing::instrument(name = "Edit webhook using postmark client", skip(self, config))]
+    pub async fn edit_webhook(
+        &self,
+        webhook_id: i64,
+        config: &WebhookConfig,
+    ) -> Result<Webhook, ClientError> {
+        let body = EditWebhookRequest {
+            url: &config.url,
+            http_auth: config.http_auth.as_ref(),
+            http_headers: &config.http_headers,
+            triggers: &config.triggers,
+        };
+        let request = self
+            .http_client
+            .put(self.endpoint(&format!("/webhooks/{webhook_id}"))?)
+            .json(&body);
+
+        self.execute(request).await
+    }
+
+    #[tracing::instrument(name = "Delete webhook using postmark client", skip(self))]
+    pub async fn delete_webhook(&self, webhook_id: i64) -> Result<ApiResponse, ClientError> {
+        let request = self
+            .http_client
+            .delete(self.endpoint(&format!("/webhooks/{webhook_id}"))?);
+
+        self.execute(request).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_webhook_deserialization() {
+        let webhook: Webhook = serde_json::from_value(json!({
+            "ID": 1234567,
+            "Url": "https://www.example.com/webhook-test-tracking",
+            "MessageStream": "outbound",
+            "HttpAuth": { "Username": "user", "Password": "pass" },
+            "HttpHeaders": [{ "Name": "name", "Value": "value" }],
+            "Triggers": {
+                "Open": { "Enabled": true, "PostFirstOpenOnly": false },
+                "Click": { "Enabled": true },
+                "Delivery": { "Enabled": true },
+                "Bounce": { "Enabled": false, "IncludeContent": false },
+                "SpamComplaint": { "Enabled": false, "IncludeContent": false },
+                "SubscriptionChange": { "Enabled": false }
+            }
+        }))
+        .unwrap();
+
+        let expected = WebhookConfig::new("https://www.example.com/webhook-test-tracking")
+            .message_stream("outbound")
+            .http_auth("user", SecretString::from("pass"))
+            .http_header("name", "value")
+            .triggers(WebhookTriggers {
+                open: OpenTrigger::enabled(false),
+                click: WebhookTrigger::enabled(),
+                delivery: WebhookTrigger::enabled(),
+                ..WebhookTriggers::default()
+            });
+        assert_eq!(webhook.id, 1234567);
+        assert_eq!(webhook.config, expected);
+    }
+
+    #[test]
+    fn test_http_auth_debug_hides_password() {
+        let auth = HttpAuth::new("user", SecretString::from("hunter2"));
+
+        assert!(!format!("{auth:?}").contains("hunter2"));
+    }
+
+    #[test]
+    fn test_http_auth_serializes_the_password() {
+        let config = WebhookConfig::new("https://example.com/hook")
+            .http_auth("user", SecretString::from("hunter2"));
+
+        let value = serde_json::to_value(&config).unwrap();
+
+        assert_eq!(
+            value["HttpAuth"],
+            json!({ "Username": "user", "Password": "hunter2" })
+        );
+    }
+
+    #[test]
+    fn test_without_http_auth_sends_empty_credentials() {
+        let config = WebhookConfig::new("https://example.com/hook").without_http_auth();
+        let body = EditWebhookRequest {
+            url: &config.url,
+            http_auth: config.http_auth.as_ref(),
+            http_headers: &config.http_headers,
+            triggers: &config.triggers,
+        };
+
+        let value = serde_json::to_value(&body).unwrap();
+
+        assert_eq!(value["HttpAuth"], json!({ "Username": "", "Password": "" }));
+    }
+}
@@ -0,0 +1,296 @@
+use crate::error::WebhookRejection;
+use crate::webhooks::WebhookEvent;
+use crate::InboundWebhook;
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use http::header::AUTHORIZATION;
+use http::HeaderMap;
+use ipnet::IpNet;
+use secrecy::{ExposeSecret, SecretString};
+use serde::de::DeserializeOwned;
+use std::net::{IpAddr, Ipv4Addr};
+
+/// The addresses Postmark posts webhooks from.
+pub const POSTMARK_WEBHOOK_IPS: [IpAddr; 4] = [
+    IpAddr::V4(Ipv4Addr::new(3, 134, 147, 250)),
+    IpAddr::V4(Ipv4Addr::new(50, 31, 156, 6)),
+    IpAddr::V4(Ipv4Addr::new(50, 31, 156, 77)),
+    IpAddr::V4(Ipv4Addr::new(18, 217, 206, 57)),
+];
+
+/// Checks that a webhook request comes from Postmark before parsing it.
+///
+/// Requests are checked against the basic auth credentials configured on the
+/// webhook and against an allowlist of source addresses. Either check is
+/// skipped when it isn't configured, but a verifier without any check rejects
+/// every request, unless it is built with
+/// [`insecure_allow_all`](WebhookVerifier::insecure_allow_all).
+///
+/// ```no_run
+/// # fn handle(request: http::Request<Vec<u8>>, peer: std::net::IpAddr) {
+/// use postmark_client::{SecretString, WebhookVerifier};
+///
+/// let verifier = WebhookVerifier::new()
+///     .basic_auth("postmark", SecretString::from("secret"))
+///     .allow_postmark_ips();
+///
+/// match verifier.verify(&request, peer) {
+///     Ok(event) => println!("received {} webhook", event.record_type()),
+///     Err(rejection) => println!("rejected with {}: {rejection}", rejection.status_code()),
+/// }
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct WebhookVerifier {
+    credentials: Option<blake3::Hash>,
+    allowed_sources: Vec<IpNet>,
+    allow_all: bool,
+}
+
+impl WebhookVerifier {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn basic_auth(mut self, username: &str, password: SecretString) -> Self {
+        self.credentials = Some(credentials_hash(username, password.expose_secret()));
+        self
+    }
+
+    /// Accepts requests from `source`, a single address or a range such as
+    /// `10.0.0.0/8`.
+    pub fn allow_source(mut self, source: impl Into<IpNet>) -> Self {
+        self.allowed_sources.push(source.into());
+        self
+    }
+
+    /// Accepts requests from [`POSTMARK_WEBHOOK_IPS`].
+    pub fn allow_postmark_ips(self) -> Self {
+        POSTMARK_WEBHOOK_IPS
+            .into_iter()
+            .fold(self, |verifier, ip| verifier.allow_source(ip))
+    }
+
+    /// Accepts every request without any check, e.g. for local development.
+    /// Anyone who finds the webhook URL can then post fake events.
+    pub fn insecure_allow_all(mut self) -> Self {
+        self.allow_all = true;
+        self
+    }
+
+    /// Checks the credentials and source of a request, without parsing it.
+    ///
+    /// `peer_addr` is the address the request came from. Behind a proxy this
+    /// is the client address the proxy reports, not the proxy itself.
+    pub fn authorize<B>(
+        &self,
+        request: &http::Request<B>,
+        peer_addr: IpAddr,
+    ) -> Result<(), WebhookRejection> {
+        self.authorize_parts(request.headers(), Some(peer_addr))
+    }
+
+    /// Whether requests are checked against allowed sources, which needs
+    /// their peer address.
+    pub fn checks_source(&self) -> bool {
+        !self.allowed_sources.is_empty()
+    }
+
+    /// Checks the headers and source of a request before its body is read.
+    /// `peer_addr` is only needed when [`Self::checks_source`].
+    pub(crate) fn authorize_parts(
+        &self,
+        headers: &HeaderMap,
+        peer_addr: Option<IpAddr>,
+    ) -> Result<(), WebhookRejection> {
+        if self.credentials.is_none() && !self.checks_source() && !self.allow_all {
+            return Err(WebhookRejection::Misconfigured(
+                "no basic auth credentials or allowed sources are configured",
+            ));
+        }
+
+        if self.checks_source() {
+            let Some(peer_addr) = peer_addr.map(|addr| addr.to_canonical()) else {
+                return Err(WebhookRejection::Misconfigured(
+                    "the peer address is needed to check the allowed sources",
+                ));
+            };
+            if !self
+                .allowed_sources
+                .iter()
+                .any(|source| source.contains(&peer_addr))
+            {
+                return Err(WebhookRejection::ForbiddenSource(peer_addr));
+            }
+        }
+
+        let Some(expected) = &self.credentials else {
+            return Ok(());
+        };
+        let (username, password) = headers
+            .get(AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(parse_basic_auth)
+            .ok_or(WebhookRejection::MissingCredentials)?;
+
+        // Comparing the hashes keeps the comparison constant-time, whatever
+        // the length of the credentials.
+        if credentials_hash(&username, &password) == *expected {
+            Ok(())
+        } else {
+            Err(WebhookRejection::InvalidCredentials)
+        }
+    }
+
+    /// Verifies an outbound webhook request and parses its event.
+    pub fn verify<B: AsRef<[u8]>>(
+        &self,
+        request: &http::Request<B>,
+        peer_addr: IpAddr,
+    ) -> Result<WebhookEvent, WebhookRejection> {
+        self.verify_as(request, peer_addr)
+    }
+
+    /// Verifies an inbound webhook request and parses the received message.
+    pub fn verify_inbound<B: AsRef<[u8]>>(
+        &self,
+        request: &http::Request<B>,
+        peer_addr: IpAddr,
+    ) -> Result<InboundWebhook, WebhookRejection> {
+        self.verify_as(request, peer_addr)
+    }
+
+    fn verify_as<T: DeserializeOwned, B: AsRef<[u8]>>(
+        &self,
+        request: &http::Request<B>,
+        peer_addr: IpAddr,
+    ) -> Result<T, WebhookRejection> {
+        self.authorize(request, peer_addr)?;
+        parse_payload(request.body().as_ref())
+    }
+}
+
+pub(crate) fn parse_payload<T: DeserializeOwned>(body: &[u8]) -> Result<T, WebhookRejection> {
+    serde_json::from_slice(body).map_err(WebhookRejection::InvalidPayload)
+}
+
+fn credentials_hash(username: &str, password: &str) -> blake3::Hash {
+    let mut hasher = blake3::Hasher::new();
+    hasher.update(username.as_bytes());
+    hasher.update(b":");
+    hasher.update(password.as_bytes());
+    hasher.finalize()
+}
+
+fn parse_basic_auth(header: &str) -> Option<(String, String)> {
+    let (scheme, encoded) = header.trim().split_once(' ')?;
+    if !scheme.eq_ignore_ascii_case("basic") {
+        return None;
+    }
+    let decoded = String::from_utf8(STANDARD.decode(encoded.trim()).ok()?).ok()?;
+    let (username, password) = decoded.split_once(':')?;
+    Some((username.to_string(), password.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use http::StatusCode;
+
+    const DELIVERY: &str = r#"{
+        "RecordType": "Delivery",
+        "MessageID": "00000000-0000-0000-0000-000000000000",
+        "Recipient": "john@example.com",
+        "DeliveredAt": "2019-11-05T16:33:54.9070259Z"
+    }"#;
+
+    fn request(authorization: Option<&str>) -> http::Request<&'static str> {
+        let mut builder = http::Request::post("/webhooks/postmark");
+        if let Some(authorization) = authorization {
+            builder = builder.header(AUTHORIZATION, authorization);
+        }
+        builder.body(DELIVERY).unwrap()
+    }
+
+    fn verifier() -> WebhookVerifier {
+        WebhookVerifier::new()
+            .basic_auth("postmark", SecretString::from("secret"))
+            .allow_postmark_ips()
+    }
+
+    fn basic(credentials: &str) -> String {
+        format!("Basic {}", STANDARD.encode(credentials))
+    }
+
+    #[test]
+    fn test_verify_accepts_matching_request() {
+        let authorization = basic("postmark:secret");
+
+        let event = verifier()
+            .verify(&request(Some(&authorization)), POSTMARK_WEBHOOK_IPS[0])
+            .unwrap();
+
+        assert_eq!(event.record_type(), "Delivery");
+    }
+
+    #[test]
+    fn test_verify_rejects_wrong_credentials() {
+        let peer = POSTMARK_WEBHOOK_IPS[1];
+
+        let missing = verifier().verify(&request(None), peer).unwrap_err();
+        let wrong = verifier()
+            .verify(&request(Some(&basic("postmark:guess"))), peer)
+            .unwrap_err();
+        let bearer = verifier()
+            .verify(&request(Some("Bearer secret")), peer)
+            .unwrap_err();
+
+        assert!(matches!(missing, WebhookRejection::MissingCredentials));
+        assert!(matches!(wrong, WebhookRejection::InvalidCredentials));
+        assert!(matches!(bearer, WebhookRejection::MissingCredentials));
+        assert_eq!(wrong.status_code(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[test]
+    fn test_verify_checks_source_ranges() {
+        let verifier = WebhookVerifier::new().allow_source("10.0.0.0/8".parse::<IpNet>().unwrap());
+        let mapped: IpAddr = "::ffff:10.1.2.3".parse().unwrap();
+
+        let allowed = verifier.authorize(&request(None), mapped);
+        let rejected = verifier
+            .authorize(&request(None), "192.168.0.1".parse().unwrap())
+            .unwrap_err();
+
+        assert!(allowed.is_ok());
+        assert!(matches!(rejected, WebhookRejection::ForbiddenSource(_)));
+        assert_eq!(rejected.status_code(), StatusCode::FORBIDDEN);
+    }
+
+    #[test]
+    fn test_verify_rejects_invalid_payload() {
+        let request = http::Request::post("/").body("not json").unwrap();
+
+        let rejection = WebhookVerifier::new()
+            .insecure_allow_all()
+            .verify(&request, POSTMARK_WEBHOOK_IPS[0])
+            .unwrap_err();
+
+        assert_eq!(rejection.status_code(), StatusCode::BAD_REQUEST);
+    }
+
+    #[test]
+    fn test_verifier_without_checks_rejects_everything() {
+        let authorization = basic("postmark:secret");
+
+        let rejection = WebhookVerifier::new()
+            .verify(&request(Some(&authorization)), POSTMARK_WEBHOOK_IPS[0])
+            .unwrap_err();
+        let allowed = WebhookVerifier::new()
+            .insecure_allow_all()
+            .verify(&request(None), "192.168.0.1".parse().unwrap());
+
+        assert!(matches!(rejection, WebhookRejection::Misconfigured(_)));
+        assert_eq!(rejection.status_code(), StatusCode::INTERNAL_SERVER_ERROR);
+        assert!(allowed.is_ok());
+    }
+}
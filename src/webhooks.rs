@@ -0,0 +1,395 @@
+//! Payloads of the webhooks Postmark posts for outbound messages.
+//!
+//! Each record type has its own struct, and [`WebhookEvent`] picks the right
+//! one from the `RecordType` of a payload. Fields Postmark adds later end up
+//! in the `extra` map of each struct rather than failing the parse.
+
+use crate::error::ClientError;
+use crate::{BounceType, ClickLocation, GeoLocation, Platform, UserAgentInfo};
+use serde::de::{self, DeserializeOwned, Deserializer};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::BTreeMap;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Delivery {
+    #[serde(rename = "MessageID")]
+    pub message_id: String,
+    pub message_stream: Option<String>,
+    #[serde(rename = "ServerID")]
+    pub server_id: Option<i64>,
+    pub recipient: String,
+    pub delivered_at: String,
+    pub details: Option<String>,
+    pub tag: Option<String>,
+    #[serde(default)]
+    pub metadata: BTreeMap<String, String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Bounce {
+    #[serde(rename = "ID")]
+    pub id: i64,
+    #[serde(rename = "Type")]
+    pub bounce_type: BounceType,
+    pub type_code: i64,
+    pub name: String,
+    #[serde(rename = "MessageID")]
+    pub message_id: Option<String>,
+    pub message_stream: Option<String>,
+    #[serde(rename = "ServerID")]
+    pub server_id: Option<i64>,
+    pub description: Option<String>,
+    pub details: Option<String>,
+    pub email: String,
+    pub from: Option<String>,
+    pub bounced_at: String,
+    pub dump_available: bool,
+    pub inactive: bool,
+    pub can_activate: bool,
+    pub subject: Option<String>,
+    /// The raw bounce message or complaint, when the webhook is set to
+    /// include it.
+    pub content: Option<String>,
+    pub tag: Option<String>,
+    #[serde(default)]
+    pub metadata: BTreeMap<String, String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Spam complaints are posted with the same fields as bounces, with a
+/// `bounce_type` of [`BounceType::SpamComplaint`].
+pub type SpamComplaint = Bounce;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Open {
+    #[serde(rename = "MessageID")]
+    pub message_id: String,
+    pub message_stream: Option<String>,
+    pub recipient: String,
+    pub received_at: String,
+    #[serde(default)]
+    pub first_open: bool,
+    pub read_seconds: Option<u32>,
+    pub platform: Platform,
+    pub user_agent: Option<String>,
+    #[serde(default)]
+    pub client: UserAgentInfo,
+    #[serde(rename = "OS", default)]
+    pub os: UserAgentInfo,
+    #[serde(default)]
+    pub geo: GeoLocation,
+    pub tag: Option<String>,
+    #[serde(default)]
+    pub metadata: BTreeMap<String, String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Click {
+    #[serde(rename = "MessageID")]
+    pub message_id: String,
+    pub message_stream: Option<String>,
+    pub recipient: String,
+    pub received_at: String,
+    pub click_location: ClickLocation,
+    pub original_link: String,
+    pub platform: Platform,
+    pub user_agent: Option<String>,
+    #[serde(default)]
+    pub client: UserAgentInfo,
+    #[serde(rename = "OS", default)]
+    pub os: UserAgentInfo,
+    #[serde(default)]
+    pub geo: GeoLocation,
+    pub tag: Option<String>,
+    #[serde(default)]
+    pub metadata: BTreeMap<String, String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Sent when an address is suppressed or reactivated on a message stream.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SubscriptionChange {
+    #[serde(rename = "MessageID")]
+    pub message_id: Option<String>,
+    pub message_stream: Option<String>,
+    #[serde(rename = "ServerID")]
+    pub server_id: Option<i64>,
+    pub recipient: String,
+    pub changed_at: String,
+    /// Who made the change: `Recipient`, `Customer` or `Admin`.
+    pub origin: Option<String>,
+    /// `true` when the address was suppressed, `false` when reactivated.
+    pub suppress_sending: bool,
+    pub suppression_reason: Option<String>,
+    pub tag: Option<String>,
+    #[serde(default)]
+    pub metadata: BTreeMap<String, String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// An outbound webhook payload of any record type.
+#[derive(Debug, Clone, PartialEq)]
+pub enum WebhookEvent {
+    Delivery(Delivery),
+    Bounce(Bounce),
+    SpamComplaint(SpamComplaint),
+    Open(Open),
+    Click(Click),
+    SubscriptionChange(SubscriptionChange),
+    /// A record type this version does not know, with the rest of its payload.
+    Unknown {
+        record_type: String,
+        payload: Map<String, Value>,
+    },
+}
+
+impl WebhookEvent {
+    pub fn parse(body: &[u8]) -> Result<Self, ClientError> {
+        serde_json::from_slice(body).map_err(ClientError::Serde)
+    }
+
+    pub fn record_type(&self) -> &str {
+        match self {
+            WebhookEvent::Delivery(_) => "Delivery",
+            WebhookEvent::Bounce(_) => "Bounce",
+            WebhookEvent::SpamComplaint(_) => "SpamComplaint",
+            WebhookEvent::Open(_) => "Open",
+            WebhookEvent::Click(_) => "Click",
+            WebhookEvent::SubscriptionChange(_) => "SubscriptionChange",
+            WebhookEvent::Unknown { record_type, .. } => record_type,
+        }
+    }
+
+    pub fn message_id(&self) -> Option<&str> {
+        match self {
+            WebhookEvent::Delivery(event) => Some(&event.message_id),
+            WebhookEvent::Bounce(event) => event.message_id.as_deref(),
+            WebhookEvent::SpamComplaint(event) => event.message_id.as_deref(),
+            WebhookEvent::Open(event) => Some(&event.message_id),
+            WebhookEvent::Click(event) => Some(&event.message_id),
+            WebhookEvent::SubscriptionChange(event) => event.message_id.as_deref(),
+            WebhookEvent::Unknown { payload, .. } => {
+                payload.get("MessageID").and_then(Value::as_str)
+            }
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for WebhookEvent {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut payload = Map::deserialize(deserializer)?;
+        let record_type = match payload.remove("RecordType") {
+            Some(Value::String(record_type)) => record_type,
+            Some(other) => {
+                return Err(de::Error::invalid_type(
+                    de::Unexpected::Other(&other.to_string()),
+                    &"a record type string",
+                ))
+            }
+            None => return Err(de::Error::missing_field("RecordType")),
+        };
+
+        Ok(match record_type.as_str() {
+            "Delivery" => WebhookEvent::Delivery(from_payload(payload)?),
+            "Bounce" => WebhookEvent::Bounce(from_payload(payload)?),
+            "SpamComplaint" => WebhookEvent::SpamComplaint(from_payload(payload)?),
+            "Open" => WebhookEvent::Open(from_payload(payload)?),
+            "Click" => WebhookEvent::Click(from_payload(payload)?),
+            "SubscriptionChange" => WebhookEvent::SubscriptionChange(from_payload(payload)?),
+            _ => WebhookEvent::Unknown {
+                record_type,
+                payload,
+            },
+        })
+    }
+}
+
+fn from_payload<T: DeserializeOwned, E: de::Error>(payload: Map<String, Value>) -> Result<T, E> {
+    serde_json::from_value(Value::Object(payload)).map_err(E::custom)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn parse(payload: Value) -> WebhookEvent {
+        serde_json::from_value(payload).unwrap()
+    }
+
+    #[test]
+    fn test_delivery_event() {
+        let event = parse(json!({
+            "RecordType": "Delivery",
+            "ServerID": 23,
+            "MessageStream": "outbound",
+            "MessageID": "00000000-0000-0000-0000-000000000000",
+            "Recipient": "john@example.com",
+            "Tag": "welcome-email",
+            "DeliveredAt": "2019-11-05T16:33:54.9070259Z",
+            "Details": "Test delivery webhook details",
+            "Metadata": { "example": "value" }
+        }));
+
+        let WebhookEvent::Delivery(delivery) = &event else {
+            panic!("expected a delivery, got {event:?}");
+        };
+        assert_eq!(delivery.recipient, "john@example.com");
+        assert_eq!(delivery.metadata["example"], "value");
+        assert!(delivery.extra.is_empty());
+        assert_eq!(event.record_type(), "Delivery");
+    }
+
+    #[test]
+    fn test_bounce_event() {
+        let event = parse(json!({
+            "RecordType": "Bounce",
+            "ID": 4323372036854775807i64,
+            "Type": "HardBounce",
+            "TypeCode": 1,
+            "Name": "Hard bounce",
+            "Tag": "Test",
+            "MessageID": "00000000-0000-0000-0000-000000000000",
+            "ServerID": 23,
+            "MessageStream": "outbound",
+            "Description": "The server was unable to deliver your message (ex: unknown user, mailbox not found).",
+            "Details": "Test bounce details",
+            "Email": "john@example.com",
+            "From": "sender@example.com",
+            "BouncedAt": "2019-11-05T16:33:54.9070259Z",
+            "DumpAvailable": true,
+            "Inactive": true,
+            "CanActivate": true,
+            "Subject": "Test subject",
+            "Content": "<Full dump of bounce>",
+            "Metadata": {}
+        }));
+
+        let WebhookEvent::Bounce(bounce) = event else {
+            panic!("expected a bounce, got {event:?}");
+        };
+        assert_eq!(bounce.bounce_type, BounceType::HardBounce);
+        assert_eq!(bounce.content.as_deref(), Some("<Full dump of bounce>"));
+    }
+
+    #[test]
+    fn test_spam_complaint_with_unrecognised_type() {
+        let event = parse(json!({
+            "RecordType": "SpamComplaint",
+            "ID": 42,
+            "Type": "SomeFutureComplaintType",
+            "TypeCode": 100001,
+            "Name": "Spam complaint",
+            "MessageID": "00000000-0000-0000-0000-000000000000",
+            "ServerID": 23,
+            "MessageStream": "outbound",
+            "Email": "john@example.com",
+            "From": "sender@example.com",
+            "BouncedAt": "2019-11-05T16:33:54.9070259Z",
+            "DumpAvailable": true,
+            "Inactive": true,
+            "CanActivate": false,
+            "Subject": "Test subject"
+        }));
+
+        let WebhookEvent::SpamComplaint(complaint) = event else {
+            panic!("expected a spam complaint, got {event:?}");
+        };
+        assert_eq!(
+            complaint.bounce_type,
+            BounceType::Other("SomeFutureComplaintType".to_string())
+        );
+        assert_eq!(complaint.email, "john@example.com");
+    }
+
+    #[test]
+    fn test_click_event() {
+        let event = parse(json!({
+            "RecordType": "Click",
+            "MessageStream": "outbound",
+            "ClickLocation": "HTML",
+            "Client": { "Name": "Chrome 35.0.1916.153", "Company": "Google", "Family": "Chrome" },
+            "OS": { "Name": "OS X 10.7 Lion", "Company": "Apple Computer, Inc.", "Family": "OS X 10" },
+            "Platform": "Desktop",
+            "UserAgent": "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_7_5)",
+            "OriginalLink": "https://example.com",
+            "Geo": { "CountryISOCode": "RS", "Country": "Serbia", "City": "Novi Sad", "IP": "8.8.8.8" },
+            "MessageID": "00000000-0000-0000-0000-000000000000",
+            "Metadata": {},
+            "ReceivedAt": "2017-10-25T15:21:11.0000000Z",
+            "Tag": "welcome-email",
+            "Recipient": "john@example.com"
+        }));
+
+        let WebhookEvent::Click(click) = event else {
+            panic!("expected a click, got {event:?}");
+        };
+        assert_eq!(click.click_location, ClickLocation::Html);
+        assert_eq!(click.geo.city.as_deref(), Some("Novi Sad"));
+    }
+
+    #[test]
+    fn test_subscription_change_event() {
+        let event = parse(json!({
+            "RecordType": "SubscriptionChange",
+            "MessageID": "00000000-0000-0000-0000-000000000000",
+            "ServerID": 23,
+            "MessageStream": "outbound",
+            "ChangedAt": "2020-02-01T10:53:34.416071Z",
+            "Recipient": "bounced-address@wildbit.com",
+            "Origin": "Recipient",
+            "SuppressSending": true,
+            "SuppressionReason": "HardBounce",
+            "Tag": "my-tag",
+            "Metadata": { "example": "value" }
+        }));
+
+        let WebhookEvent::SubscriptionChange(change) = event else {
+            panic!("expected a subscription change, got {event:?}");
+        };
+        assert!(change.suppress_sending);
+        assert_eq!(change.suppression_reason.as_deref(), Some("HardBounce"));
+    }
+
+    #[test]
+    fn test_unknown_fields_and_record_types_are_kept() {
+        let event = parse(json!({
+            "RecordType": "Open",
+            "MessageID": "00000000-0000-0000-0000-000000000000",
+            "Recipient": "john@example.com",
+            "ReceivedAt": "2019-11-05T16:33:54.9070259Z",
+            "FirstOpen": true,
+            "Platform": "Smartwatch",
+            "Brand": "new field"
+        }));
+        let WebhookEvent::Open(open) = event else {
+            panic!("expected an open, got {event:?}");
+        };
+        assert_eq!(open.platform, Platform::Unknown);
+        assert_eq!(open.extra["Brand"], "new field");
+
+        let event = parse(json!({ "RecordType": "Forward", "MessageID": "abc" }));
+        assert_eq!(event.record_type(), "Forward");
+        assert_eq!(event.message_id(), Some("abc"));
+    }
+
+    #[test]
+    fn test_missing_record_type_is_an_error() {
+        let result = WebhookEvent::parse(br#"{"MessageID": "abc"}"#);
+
+        assert!(matches!(result, Err(ClientError::Serde(_))));
+    }
+}
@@ -4,10 +4,22 @@ mod tests {
     use fake::faker::internet::en::SafeEmail;
     use fake::faker::lorem::en::{Sentence};
     use fake::Fake;
-    use postmark_client::{Client, Email, OutboundEmailBody, SendEmailResponse};
+    use postmark_client::{
+        AccountClient, BounceFilter, BounceType, Client, InboundMessageFilter, InboundMessageStatus,
+        OutboundMessageFilter, Platform, Email, OutboundEmailBody, SendEmailResponse, TemplateBody, TemplateFilter,
+        TemplateSync, TemplateSyncAction, TemplateSyncConflict, TemplateType, TemplatedEmailBody, TrackingEventFilter,
+        ContentTrigger, WebhookConfig, WebhookTriggers, SuppressionFilter, SuppressionOrigin,
+        SuppressionReason, MessageStreamBody, MessageStreamFilter, MessageStreamType, ServerBody,
+        TrackLink, DeliveryType, DomainBody, DnsRecordType,
+        SenderSignatureBody, StatsFilter,
+    };
     use reqwest::Url;
     use secrecy::SecretString;
-    use wiremock::matchers::{any, header, header_exists, method, path};
+    use serde_json::json;
+    use tempfile::TempDir;
+    use wiremock::matchers::{
+        any, body_partial_json, header, header_exists, method, path, query_param,
+    };
     use wiremock::{Mock, MockServer, Request, ResponseTemplate};
 
     fn build_outbound_email_body() -> OutboundEmailBody{
@@ -19,6 +31,15 @@ mod tests {
             .build()
     }
 
+    fn account_client(base_url: &str) -> AccountClient {
+        AccountClient::builder()
+            .base_url(Url::parse(base_url).expect("Failed to parse base uri"))
+            .auth_token(SecretString::from("account-token"))
+            .timeout(std::time::Duration::from_secs(1))
+            .build()
+            .unwrap()
+    }
+
     /// Get a test instance of `EmailClient`.
     fn email_client(base_url: &str) -> Client {
         let base_url = Url::parse(base_url).expect("Failed to parse base uri");
@@ -113,6 +134,1510 @@ mod tests {
         assert_err!(outcome);
     }
 
+    #[tokio::test]
+    async fn send_with_template_posts_template_alias_and_model() {
+        let mock_server = MockServer::start().await;
+        let email_client = email_client(&mock_server.uri());
+
+        Mock::given(header_exists("X-Postmark-Server-Token"))
+            .and(path("/email/withTemplate"))
+            .and(method("POST"))
+            .and(body_partial_json(json!({
+                "TemplateAlias": "welcome",
+                "TemplateModel": { "name": "Jane" }
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(SendEmailResponse::default()))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let to = Email::parse(SafeEmail().fake::<String>().as_str()).unwrap();
+        let body = TemplatedEmailBody::builder(to, "welcome", json!({ "name": "Jane" })).build();
+        let outcome = email_client.send_with_template(&body).await;
+
+        assert_ok!(outcome);
+    }
+
+    #[tokio::test]
+    async fn send_batch_with_templates_wraps_messages() {
+        let mock_server = MockServer::start().await;
+        let email_client = email_client(&mock_server.uri());
+
+        Mock::given(path("/email/batchWithTemplates"))
+            .and(method("POST"))
+            .and(body_partial_json(json!({ "Messages": [{ "TemplateId": 42 }] })))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(vec![SendEmailResponse::default()]),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let to = Email::parse(SafeEmail().fake::<String>().as_str()).unwrap();
+        let bodies = vec![TemplatedEmailBody::builder(to, 42, json!({})).build()];
+        let outcome = email_client.send_batch_with_templates(&bodies).await;
+
+        assert_eq!(outcome.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn list_templates_sends_filter_as_query() {
+        let mock_server = MockServer::start().await;
+        let email_client = email_client(&mock_server.uri());
+
+        Mock::given(header_exists("X-Postmark-Server-Token"))
+            .and(path("/templates"))
+            .and(method("GET"))
+            .and(query_param("Count", "20"))
+            .and(query_param("Offset", "0"))
+            .and(query_param("TemplateType", "Layout"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "TotalCount": 1,
+                "Templates": [{
+                    "Active": true,
+                    "TemplateId": 1234,
+                    "Name": "Base layout",
+                    "Alias": "base",
+                    "TemplateType": "Layout",
+                    "LayoutTemplate": null
+                }]
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let filter = TemplateFilter::new()
+            .count(20)
+            .template_type(TemplateType::Layout);
+        let list = email_client.list_templates(&filter).await.unwrap();
+
+        assert_eq!(list.total_count, 1);
+        assert_eq!(list.templates[0].alias.as_deref(), Some("base"));
+    }
+
+    #[tokio::test]
+    async fn edit_template_puts_to_the_alias_path() {
+        let mock_server = MockServer::start().await;
+        let email_client = email_client(&mock_server.uri());
+
+        Mock::given(path("/templates/welcome"))
+            .and(method("PUT"))
+            .and(body_partial_json(json!({ "Subject": "Welcome!" })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "TemplateId": 1234,
+                "Name": "Welcome",
+                "Alias": "welcome",
+                "Active": true,
+                "TemplateType": "Standard",
+                "LayoutTemplate": null
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let body = TemplateBody::builder().subject("Welcome!").build();
+        let outcome = email_client.edit_template("welcome", &body).await;
+
+        assert_eq!(outcome.unwrap().template_id, 1234);
+    }
+
+    #[tokio::test]
+    async fn delete_template_fails_with_authentication_error_on_401() {
+        let mock_server = MockServer::start().await;
+        let email_client = email_client(&mock_server.uri());
+
+        Mock::given(path("/templates/1234"))
+            .and(method("DELETE"))
+            .respond_with(ResponseTemplate::new(401))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let outcome = email_client.delete_template(1234).await;
+
+        assert!(matches!(
+            outcome,
+            Err(postmark_client::error::ClientError::Authentication(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn validate_template_sends_test_render_model() {
+        let mock_server = MockServer::start().await;
+        let email_client = email_client(&mock_server.uri());
+
+        Mock::given(path("/templates/validate"))
+            .and(method("POST"))
+            .and(body_partial_json(json!({
+                "Subject": "Hi {{name}}",
+                "TestRenderModel": { "name": "Jane" }
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "AllContentIsValid": true,
+                "Subject": {
+                    "ContentIsValid": true,
+                    "ValidationErrors": [],
+                    "RenderedContent": "Hi Jane"
+                },
+                "SuggestedTemplateModel": { "name": "name_Value" }
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let body = TemplateBody::builder().subject("Hi {{name}}").build();
+        let model = json!({ "name": "Jane" });
+        let validation = email_client
+            .validate_template(&body, &model)
+            .await
+            .unwrap();
+
+        assert!(validation.is_valid());
+        assert!(validation.compare_model(&model).unwrap().is_match());
+    }
+
+    #[tokio::test]
+    async fn preview_template_push_uses_account_token_without_performing_changes() {
+        let mock_server = MockServer::start().await;
+        let account_client = account_client(&mock_server.uri());
+
+        Mock::given(header("X-Postmark-Account-Token", "account-token"))
+            .and(path("/templates/push"))
+            .and(method("PUT"))
+            .and(body_partial_json(json!({
+                "SourceServerID": 1,
+                "DestinationServerID": 2,
+                "PerformChanges": false
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "TotalCount": 1,
+                "Templates": [{
+                    "Action": "Edit",
+                    "TemplateId": 7270,
+                    "Alias": "welcome",
+                    "Name": "Welcome",
+                    "TemplateType": "Standard"
+                }]
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let result = account_client.preview_template_push(1, 2).await.unwrap();
+
+        assert_eq!(result.total_count, 1);
+    }
+
+    #[tokio::test]
+    async fn sync_templates_dry_run_reports_changes_without_applying_them() {
+        let mock_server = MockServer::start().await;
+        let email_client = email_client(&mock_server.uri());
+
+        Mock::given(path("/templates"))
+            .and(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "TotalCount": 1,
+                "Templates": [{
+                    "Active": true,
+                    "TemplateId": 7,
+                    "Name": "Old",
+                    "Alias": "old",
+                    "TemplateType": "Standard",
+                    "LayoutTemplate": null
+                }]
+            })))
+            .mount(&mock_server)
+            .await;
+        Mock::given(path("/templates/7"))
+            .and(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "TemplateId": 7,
+                "Name": "Old",
+                "Alias": "old",
+                "Subject": "Old",
+                "HtmlBody": "<p>Old</p>",
+                "TextBody": null,
+                "AssociatedServerId": 1,
+                "Active": true,
+                "TemplateType": "Standard",
+                "LayoutTemplate": null
+            })))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(500))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("DELETE"))
+            .respond_with(ResponseTemplate::new(500))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+
+        let dir = TempDir::new().unwrap();
+        write_template_files(&dir, "welcome", &[("subject", "Hi"), ("html", "<p>Hi</p>")]);
+        let sync = TemplateSync::from_dir(dir.path()).unwrap().dry_run(true);
+
+        let report = email_client.sync_templates(&sync).await.unwrap();
+
+        let actions: Vec<_> = report
+            .changes
+            .iter()
+            .map(|change| (change.action, change.alias.as_str()))
+            .collect();
+        assert_eq!(
+            actions,
+            vec![
+                (TemplateSyncAction::Create, "welcome"),
+                (TemplateSyncAction::Delete, "old")
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn sync_templates_applies_creates_edits_and_deletes() {
+        let mock_server = MockServer::start().await;
+        let email_client = email_client(&mock_server.uri());
+        let summary = json!({
+            "TemplateId": 1,
+            "Name": "Template",
+            "Alias": "template",
+            "Active": true,
+            "TemplateType": "Standard",
+            "LayoutTemplate": null
+        });
+
+        Mock::given(path("/templates"))
+            .and(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "TotalCount": 2,
+                "Templates": [
+                    {
+                        "Active": true,
+                        "TemplateId": 7,
+                        "Name": "Old",
+                        "Alias": "old",
+                        "TemplateType": "Standard",
+                        "LayoutTemplate": null
+                    },
+                    {
+                        "Active": true,
+                        "TemplateId": 8,
+                        "Name": "Welcome",
+                        "Alias": "welcome",
+                        "TemplateType": "Standard",
+                        "LayoutTemplate": null
+                    }
+                ]
+            })))
+            .mount(&mock_server)
+            .await;
+        Mock::given(path("/templates/7"))
+            .and(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "TemplateId": 7,
+                "Name": "Old",
+                "Alias": "old",
+                "Subject": "Old",
+                "HtmlBody": "<p>Old</p>",
+                "TextBody": null,
+                "AssociatedServerId": 1,
+                "Active": true,
+                "TemplateType": "Standard",
+                "LayoutTemplate": null
+            })))
+            .mount(&mock_server)
+            .await;
+        Mock::given(path("/templates/8"))
+            .and(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "TemplateId": 8,
+                "Name": "Welcome",
+                "Alias": "welcome",
+                "Subject": "Hi",
+                "HtmlBody": "<p>Hi</p>",
+                "TextBody": "Hi",
+                "AssociatedServerId": 1,
+                "Active": true,
+                "TemplateType": "Standard",
+                "LayoutTemplate": null
+            })))
+            .mount(&mock_server)
+            .await;
+        Mock::given(path("/templates"))
+            .and(method("POST"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(summary.clone()))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(path("/templates/welcome"))
+            .and(method("PUT"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(summary))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(path("/templates/7"))
+            .and(method("DELETE"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "ErrorCode": 0,
+                "Message": "Template 7 removed."
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        // The local welcome template no longer has a text part, and a new
+        // layout is added.
+        let dir = TempDir::new().unwrap();
+        write_template_files(
+            &dir,
+            "welcome",
+            &[
+                ("meta.json", r#"{"name": "Welcome"}"#),
+                ("subject", "Hi"),
+                ("html", "<p>Hi</p>"),
+            ],
+        );
+        write_template_files(&dir, "layouts/base", &[("html", "{{{@content}}}")]);
+        let sync = TemplateSync::from_dir(dir.path()).unwrap();
+
+        let report = email_client.sync_templates(&sync).await.unwrap();
+        assert_eq!(report.changes.len(), 3);
+
+        let requests = mock_server.received_requests().await.unwrap();
+        let body_of = |verb: &str| -> serde_json::Value {
+            let request = requests
+                .iter()
+                .find(|request| request.method.as_str() == verb)
+                .unwrap();
+            serde_json::from_slice(&request.body).unwrap()
+        };
+        assert_eq!(
+            body_of("POST"),
+            json!({
+                "Name": "base",
+                "Alias": "base",
+                "TemplateType": "Layout",
+                "HtmlBody": "{{{@content}}}"
+            })
+        );
+        assert_eq!(
+            body_of("PUT"),
+            json!({
+                "Name": "Welcome",
+                "Subject": "Hi",
+                "LayoutTemplate": "",
+                "HtmlBody": "<p>Hi</p>",
+                "TextBody": ""
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn sync_templates_creates_layouts_first_and_deletes_them_last() {
+        let mock_server = MockServer::start().await;
+        let email_client = email_client(&mock_server.uri());
+
+        mount_server_templates(
+            &mock_server,
+            vec![
+                server_template(7, "old", "Standard", Some("old-base")),
+                server_template(8, "old-base", "Layout", None),
+                server_template(9, "welcome", "Standard", Some("old-base")),
+            ],
+        )
+        .await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(summary_of(1, "created")))
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("PUT"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(summary_of(9, "welcome")))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("DELETE"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "ErrorCode": 0,
+                "Message": "Template removed."
+            })))
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+
+        // Both templates move to a new layout, which replaces the old one.
+        let dir = TempDir::new().unwrap();
+        let template_files = [
+            ("meta.json", r#"{"layout": "base"}"#),
+            ("subject", "Subject"),
+            ("html", "<p>Body</p>"),
+        ];
+        write_template_files(&dir, "welcome", &template_files);
+        write_template_files(&dir, "reset", &template_files);
+        write_template_files(&dir, "layouts/base", &[("html", "{{{@content}}}")]);
+        let sync = TemplateSync::from_dir(dir.path()).unwrap();
+
+        let report = email_client.sync_templates(&sync).await.unwrap();
+        assert!(report.conflicts.is_empty());
+
+        let requests = mock_server.received_requests().await.unwrap();
+        let changes: Vec<_> = requests
+            .iter()
+            .filter(|request| request.method.as_str() != "GET")
+            .map(|request| {
+                let alias = serde_json::from_slice::<serde_json::Value>(&request.body)
+                    .ok()
+                    .and_then(|body| body["Alias"].as_str().map(str::to_string));
+                (
+                    request.method.to_string(),
+                    request.url.path().to_string(),
+                    alias,
+                )
+            })
+            .collect();
+        let change = |verb: &str, path: &str, alias: Option<&str>| {
+            (verb.to_string(), path.to_string(), alias.map(str::to_string))
+        };
+        assert_eq!(
+            changes,
+            vec![
+                change("POST", "/templates", Some("base")),
+                change("POST", "/templates", Some("reset")),
+                change("PUT", "/templates/welcome", None),
+                change("DELETE", "/templates/7", None),
+                change("DELETE", "/templates/8", None),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn sync_templates_refuses_to_change_a_template_type() {
+        let mock_server = MockServer::start().await;
+        let email_client = email_client(&mock_server.uri());
+
+        mount_server_templates(
+            &mock_server,
+            vec![
+                server_template(7, "base", "Standard", None),
+                server_template(8, "old", "Standard", None),
+            ],
+        )
+        .await;
+        Mock::given(method("DELETE"))
+            .respond_with(ResponseTemplate::new(500))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+
+        let dir = TempDir::new().unwrap();
+        write_template_files(&dir, "layouts/base", &[("html", "{{{@content}}}")]);
+        let sync = TemplateSync::from_dir(dir.path()).unwrap();
+
+        let error = email_client.sync_templates(&sync).await.unwrap_err();
+        let postmark_client::error::ClientError::Configuration(message) = error else {
+            panic!("unexpected error: {error}");
+        };
+        assert!(message.contains("'base'"));
+
+        let report = email_client
+            .sync_templates(&sync.dry_run(true))
+            .await
+            .unwrap();
+        assert_eq!(
+            report.conflicts,
+            vec![TemplateSyncConflict::TypeMismatch {
+                alias: "base".to_string(),
+                local: TemplateType::Layout,
+                server: TemplateType::Standard,
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn sync_templates_keeps_layouts_that_templates_still_use() {
+        let mock_server = MockServer::start().await;
+        let email_client = email_client(&mock_server.uri());
+
+        let mut unaliased = server_template(9, "unaliased", "Standard", Some("base"));
+        unaliased["Alias"] = serde_json::Value::Null;
+        unaliased["Name"] = json!("Legacy");
+        mount_server_templates(
+            &mock_server,
+            vec![
+                server_template(7, "base", "Layout", None),
+                server_template(8, "welcome", "Standard", Some("base")),
+                unaliased,
+            ],
+        )
+        .await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(500))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("DELETE"))
+            .respond_with(ResponseTemplate::new(500))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+
+        // The local welcome template still uses the base layout, which is
+        // gone locally.
+        let dir = TempDir::new().unwrap();
+        write_template_files(
+            &dir,
+            "welcome",
+            &[
+                ("meta.json", r#"{"layout": "base"}"#),
+                ("subject", "Hi"),
+                ("html", "<p>Hi</p>"),
+            ],
+        );
+        let sync = TemplateSync::from_dir(dir.path()).unwrap();
+
+        let error = email_client.sync_templates(&sync).await.unwrap_err();
+        assert!(matches!(error, postmark_client::error::ClientError::Configuration(_)));
+
+        let report = email_client
+            .sync_templates(&sync.dry_run(true))
+            .await
+            .unwrap();
+        assert_eq!(
+            report.conflicts,
+            vec![
+                TemplateSyncConflict::LayoutInUse {
+                    layout: "base".to_string(),
+                    template: "welcome".to_string(),
+                },
+                TemplateSyncConflict::LayoutInUse {
+                    layout: "base".to_string(),
+                    template: "Legacy".to_string(),
+                },
+            ]
+        );
+    }
+
+    fn write_template_files(dir: &TempDir, alias: &str, files: &[(&str, &str)]) {
+        let template_dir = dir.path().join(alias);
+        std::fs::create_dir_all(&template_dir).unwrap();
+        for (file, content) in files {
+            std::fs::write(template_dir.join(file), content).unwrap();
+        }
+    }
+
+    fn summary_of(template_id: i64, alias: &str) -> serde_json::Value {
+        json!({
+            "TemplateId": template_id,
+            "Name": alias,
+            "Alias": alias,
+            "Active": true,
+            "TemplateType": "Standard",
+            "LayoutTemplate": null
+        })
+    }
+
+    fn server_template(
+        template_id: i64,
+        alias: &str,
+        template_type: &str,
+        layout: Option<&str>,
+    ) -> serde_json::Value {
+        json!({
+            "TemplateId": template_id,
+            "Name": alias,
+            "Alias": alias,
+            "Subject": if template_type == "Standard" { Some("Subject") } else { None },
+            "HtmlBody": "<p>Old</p>",
+            "TextBody": null,
+            "AssociatedServerId": 1,
+            "Active": true,
+            "TemplateType": template_type,
+            "LayoutTemplate": layout
+        })
+    }
+
+    /// Serves `templates` from the listing and from their own endpoints.
+    async fn mount_server_templates(mock_server: &MockServer, templates: Vec<serde_json::Value>) {
+        Mock::given(path("/templates"))
+            .and(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "TotalCount": templates.len(),
+                "Templates": templates
+            })))
+            .mount(mock_server)
+            .await;
+        for template in templates {
+            Mock::given(path(format!("/templates/{}", template["TemplateId"])))
+                .and(method("GET"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(template))
+                .mount(mock_server)
+                .await;
+        }
+    }
+
+    #[tokio::test]
+    async fn list_bounces_sends_typed_filters() {
+        let mock_server = MockServer::start().await;
+        let email_client = email_client(&mock_server.uri());
+
+        Mock::given(header_exists("X-Postmark-Server-Token"))
+            .and(path("/bounces"))
+            .and(method("GET"))
+            .and(query_param("type", "HardBounce"))
+            .and(query_param("inactive", "true"))
+            .and(query_param("messagestream", "outbound"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "TotalCount": 1,
+                "Bounces": [{
+                    "ID": 42,
+                    "Type": "HardBounce",
+                    "TypeCode": 1,
+                    "Name": "Hard bounce",
+                    "Email": "jane@example.com",
+                    "BouncedAt": "2014-01-15T16:09:19.6421112-05:00",
+                    "DumpAvailable": true,
+                    "Inactive": true,
+                    "CanActivate": true
+                }]
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let filter = BounceFilter::new()
+            .bounce_type(BounceType::HardBounce)
+            .inactive(true)
+            .message_stream("outbound");
+        let list = email_client.list_bounces(&filter).await.unwrap();
+
+        assert_eq!(list.bounces[0].id, 42);
+        assert!(list.bounces[0].inactive);
+    }
+
+    #[tokio::test]
+    async fn activate_bounce_puts_to_the_bounce_path() {
+        let mock_server = MockServer::start().await;
+        let email_client = email_client(&mock_server.uri());
+
+        Mock::given(path("/bounces/42/activate"))
+            .and(method("PUT"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "Message": "OK",
+                "Bounce": {
+                    "ID": 42,
+                    "Type": "HardBounce",
+                    "TypeCode": 1,
+                    "Name": "Hard bounce",
+                    "Email": "jane@example.com",
+                    "BouncedAt": "2014-01-15T16:09:19.6421112-05:00",
+                    "DumpAvailable": true,
+                    "Inactive": false,
+                    "CanActivate": true
+                }
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let activation = email_client.activate_bounce(42).await.unwrap();
+
+        assert!(!activation.bounce.inactive);
+    }
+
+    #[tokio::test]
+    async fn delivery_stats_returns_per_type_counts() {
+        let mock_server = MockServer::start().await;
+        let email_client = email_client(&mock_server.uri());
+
+        Mock::given(header_exists("X-Postmark-Server-Token"))
+            .and(path("/deliverystats"))
+            .and(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "InactiveMails": 3,
+                "Bounces": [
+                    { "Name": "All", "Count": 5 },
+                    { "Type": "HardBounce", "Name": "Hard bounce", "Count": 5 }
+                ]
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let stats = email_client.delivery_stats().await.unwrap();
+
+        assert_eq!(stats.inactive_mails, 3);
+        assert_eq!(stats.count(BounceType::HardBounce), 5);
+    }
+
+    #[tokio::test]
+    async fn search_outbound_messages_sends_metadata_filters() {
+        let mock_server = MockServer::start().await;
+        let email_client = email_client(&mock_server.uri());
+
+        Mock::given(path("/messages/outbound"))
+            .and(method("GET"))
+            .and(query_param("recipient", "jane@example.com"))
+            .and(query_param("metadata_order", "5454"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "TotalCount": 1,
+                "Messages": [{
+                    "Tag": null,
+                    "MessageID": "07311c54-0687-4ab9-b034-b54b5bad88ba",
+                    "MessageStream": "outbound",
+                    "To": [{ "Email": "jane@example.com", "Name": null }],
+                    "Recipients": ["jane@example.com"],
+                    "ReceivedAt": "2014-02-14T11:12:54.8054242-05:00",
+                    "From": "sender@example.com",
+                    "Subject": "Order #5454",
+                    "Status": "Sent",
+                    "TrackOpens": true,
+                    "TrackLinks": "None",
+                    "Metadata": { "order": "5454" }
+                }]
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let filter = OutboundMessageFilter::new()
+            .recipient("jane@example.com")
+            .metadata("order", "5454");
+        let list = email_client.search_outbound_messages(&filter).await.unwrap();
+
+        assert_eq!(list.messages[0].recipients, vec!["jane@example.com"]);
+    }
+
+    #[tokio::test]
+    async fn search_opens_sends_tracking_filters() {
+        let mock_server = MockServer::start().await;
+        let email_client = email_client(&mock_server.uri());
+
+        Mock::given(path("/messages/outbound/opens"))
+            .and(method("GET"))
+            .and(query_param("tag", "welcome-email"))
+            .and(query_param("platform", "Mobile"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "TotalCount": 0,
+                "Opens": []
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let filter = TrackingEventFilter::new()
+            .tag("welcome-email")
+            .platform(Platform::Mobile);
+        let opens = email_client.search_opens(&filter).await.unwrap();
+
+        assert_eq!(opens.total_count, 0);
+    }
+
+    #[tokio::test]
+    async fn get_message_clicks_pages_through_a_single_message() {
+        let mock_server = MockServer::start().await;
+        let email_client = email_client(&mock_server.uri());
+
+        Mock::given(path("/messages/outbound/clicks/abc-123"))
+            .and(method("GET"))
+            .and(query_param("count", "50"))
+            .and(query_param("offset", "50"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "TotalCount": 51,
+                "Clicks": [{
+                    "ClickLocation": "Text",
+                    "Platform": "Desktop",
+                    "OriginalLink": "https://example.com",
+                    "MessageID": "abc-123",
+                    "ReceivedAt": "2014-02-14T11:12:54.8054242-05:00",
+                    "Recipient": "john@domain.com"
+                }]
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let clicks = email_client
+            .get_message_clicks("abc-123", 50, 50)
+            .await
+            .unwrap();
+
+        assert_eq!(clicks.clicks[0].original_link, "https://example.com");
+    }
+
+    #[tokio::test]
+    async fn failed_inbound_messages_can_be_retried() {
+        let mock_server = MockServer::start().await;
+        let email_client = email_client(&mock_server.uri());
+
+        Mock::given(path("/messages/inbound"))
+            .and(method("GET"))
+            .and(query_param("status", "failed"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "TotalCount": 1,
+                "InboundMessages": [{
+                    "From": "john@example.com",
+                    "FromName": "John",
+                    "FromFull": { "Email": "john@example.com", "Name": "John" },
+                    "To": "support@inbound.example.com",
+                    "ToFull": [{ "Email": "support@inbound.example.com", "Name": "" }],
+                    "Subject": "Help",
+                    "Date": "Thu, 5 Apr 2012 16:59:01 +0200",
+                    "MessageID": "792d4d72",
+                    "Status": "Failed",
+                    "Attachments": [{
+                        "Name": "notes.txt",
+                        "ContentType": "text/plain",
+                        "ContentLength": 5
+                    }]
+                }]
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(path("/messages/inbound/792d4d72/retry"))
+            .and(method("PUT"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "ErrorCode": 0,
+                "Message": "Successfully rescheduled failed message: 792d4d72."
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let filter = InboundMessageFilter::new().status(InboundMessageStatus::Failed);
+        let list = email_client.search_inbound_messages(&filter).await.unwrap();
+        for message in &list.inbound_messages {
+            let outcome = email_client
+                .retry_inbound_message(&message.message_id)
+                .await
+                .unwrap();
+            assert_eq!(outcome.error_code, 0);
+        }
+    }
+
+    #[tokio::test]
+    async fn webhooks_are_created_and_edited_with_their_triggers() {
+        let mock_server = MockServer::start().await;
+        let email_client = email_client(&mock_server.uri());
+        let webhook = json!({
+            "ID": 1234567,
+            "Url": "https://www.example.com/webhooks",
+            "MessageStream": "outbound",
+            "HttpAuth": { "Username": "user", "Password": "pass" },
+            "HttpHeaders": [],
+            "Triggers": {
+                "Open": { "Enabled": false, "PostFirstOpenOnly": false },
+                "Click": { "Enabled": false },
+                "Delivery": { "Enabled": false },
+                "Bounce": { "Enabled": true, "IncludeContent": true },
+                "SpamComplaint": { "Enabled": false, "IncludeContent": false },
+                "SubscriptionChange": { "Enabled": false }
+            }
+        });
+
+        Mock::given(path("/webhooks"))
+            .and(method("POST"))
+            .and(body_partial_json(json!({
+                "MessageStream": "outbound",
+                "HttpAuth": { "Username": "user", "Password": "pass" },
+                "Triggers": { "Bounce": { "Enabled": true, "IncludeContent": true } }
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(&webhook))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(path("/webhooks/1234567"))
+            .and(method("PUT"))
+            .and(|request: &Request| {
+                let body: serde_json::Value = serde_json::from_slice(&request.body).unwrap();
+                body.get("MessageStream").is_none()
+            })
+            .respond_with(ResponseTemplate::new(200).set_body_json(&webhook))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let config = WebhookConfig::new("https://www.example.com/webhooks")
+            .message_stream("outbound")
+            .http_auth("user", SecretString::from("pass"))
+            .triggers(WebhookTriggers {
+                bounce: ContentTrigger::enabled(true),
+                ..WebhookTriggers::default()
+            });
+        let created = email_client.create_webhook(&config).await.unwrap();
+        assert_eq!(created.config, config);
+
+        let edited = email_client.edit_webhook(created.id, &config).await;
+        assert_ok!(edited);
+    }
+
+    #[tokio::test]
+    async fn list_suppressions_sends_filters_for_the_stream() {
+        let mock_server = MockServer::start().await;
+        let email_client = email_client(&mock_server.uri());
+
+        Mock::given(path("/message-streams/outbound/suppressions/dump"))
+            .and(method("GET"))
+            .and(query_param("SuppressionReason", "HardBounce"))
+            .and(query_param("Origin", "Recipient"))
+            .and(query_param("EmailAddress", "john@example.com"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "Suppressions": [{
+                    "EmailAddress": "john@example.com",
+                    "SuppressionReason": "HardBounce",
+                    "Origin": "Recipient",
+                    "CreatedAt": "2019-12-10T08:58:33-05:00"
+                }]
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let email = Email::parse("john@example.com").unwrap();
+        let filter = SuppressionFilter::new()
+            .suppression_reason(SuppressionReason::HardBounce)
+            .origin(SuppressionOrigin::Recipient)
+            .email_address(&email);
+        let list = email_client
+            .list_suppressions("outbound", &filter)
+            .await
+            .unwrap();
+
+        assert_eq!(list.suppressions[0].origin, SuppressionOrigin::Recipient);
+    }
+
+    #[tokio::test]
+    async fn delete_suppressions_posts_addresses() {
+        let mock_server = MockServer::start().await;
+        let email_client = email_client(&mock_server.uri());
+
+        Mock::given(path("/message-streams/broadcasts/suppressions/delete"))
+            .and(method("POST"))
+            .and(body_partial_json(json!({
+                "Suppressions": [{ "EmailAddress": "john@example.com" }]
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "Suppressions": [{
+                    "EmailAddress": "john@example.com",
+                    "Status": "Deleted",
+                    "Message": null
+                }]
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let emails = vec![Email::parse("john@example.com").unwrap()];
+        let results = email_client
+            .delete_suppressions("broadcasts", &emails)
+            .await
+            .unwrap();
+
+        assert_eq!(results.failed().count(), 0);
+    }
+
+    #[tokio::test]
+    async fn create_message_stream_posts_id_and_type() {
+        let mock_server = MockServer::start().await;
+        let email_client = email_client(&mock_server.uri());
+
+        Mock::given(path("/message-streams"))
+            .and(method("POST"))
+            .and(body_partial_json(json!({
+                "ID": "newsletters",
+                "Name": "Newsletters",
+                "MessageStreamType": "Broadcasts"
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "ID": "newsletters",
+                "ServerID": 123457,
+                "Name": "Newsletters",
+                "Description": null,
+                "MessageStreamType": "Broadcasts",
+                "CreatedAt": "2020-07-01T00:00:00-04:00",
+                "UpdatedAt": null,
+                "ArchivedAt": null,
+                "ExpectedPurgeDate": null,
+                "SubscriptionManagementConfiguration": { "UnsubscribeHandlingType": "Postmark" }
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let body = MessageStreamBody::builder()
+            .id("newsletters")
+            .name("Newsletters")
+            .message_stream_type(MessageStreamType::Broadcasts)
+            .build();
+        let stream = email_client.create_message_stream(&body).await.unwrap();
+
+        assert_eq!(stream.id, "newsletters");
+    }
+
+    #[tokio::test]
+    async fn list_message_streams_sends_type_filter() {
+        let mock_server = MockServer::start().await;
+        let email_client = email_client(&mock_server.uri());
+
+        Mock::given(path("/message-streams"))
+            .and(method("GET"))
+            .and(query_param("MessageStreamType", "Inbound"))
+            .and(query_param("IncludeArchivedStreams", "true"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "MessageStreams": [],
+                "TotalCount": 0
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let filter = MessageStreamFilter::new()
+            .message_stream_type(MessageStreamType::Inbound)
+            .include_archived_streams(true);
+        let outcome = email_client.list_message_streams(&filter).await;
+
+        assert_ok!(outcome);
+    }
+
+    #[tokio::test]
+    async fn send_email_posts_the_message_stream() {
+        let mock_server = MockServer::start().await;
+        let email_client = email_client(&mock_server.uri());
+
+        Mock::given(path("/email"))
+            .and(method("POST"))
+            .and(body_partial_json(json!({ "MessageStream": "broadcasts" })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "To": "receiver@example.com",
+                "SubmittedAt": "2014-02-17T07:25:01.4178645-05:00",
+                "MessageID": "0a129aee-e1cd-480d-b08d-4f48548ff48d",
+                "ErrorCode": 0,
+                "Message": "OK"
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let body = OutboundEmailBody::builder(Email::parse(&SafeEmail().fake::<String>()).unwrap())
+            .subject("Our newsletter")
+            .text_body("News")
+            .message_stream("broadcasts")
+            .build();
+        let outcome = email_client.send(&body).await;
+
+        assert_ok!(outcome);
+    }
+
+    #[tokio::test]
+    async fn edit_server_puts_only_the_changed_settings() {
+        let mock_server = MockServer::start().await;
+        let email_client = email_client(&mock_server.uri());
+
+        Mock::given(path("/server"))
+            .and(method("PUT"))
+            .and(header_exists("X-Postmark-Server-Token"))
+            .and(|request: &Request| {
+                let body: serde_json::Value = serde_json::from_slice(&request.body).unwrap();
+                body == json!({ "TrackOpens": true, "TrackLinks": "HtmlAndText" })
+            })
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "ID": 1,
+                "Name": "Production",
+                "ApiTokens": ["server token"],
+                "Color": "green",
+                "SmtpApiActivated": true,
+                "RawEmailEnabled": false,
+                "DeliveryType": "Live",
+                "InboundSpamThreshold": 5,
+                "PostFirstOpenOnly": false,
+                "TrackOpens": true,
+                "TrackLinks": "HtmlAndText",
+                "IncludeBounceContentInHook": true
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let body = ServerBody::builder()
+            .track_opens(true)
+            .track_links(TrackLink::HtmlAndText)
+            .build();
+        let server = email_client.edit_server(&body).await.unwrap();
+
+        assert!(server.track_opens);
+        assert_eq!(server.track_links, TrackLink::HtmlAndText);
+    }
+
+    #[tokio::test]
+    async fn created_server_token_builds_a_working_client() {
+        let mock_server = MockServer::start().await;
+        let account_client = account_client(&mock_server.uri());
+
+        Mock::given(path("/servers"))
+            .and(method("POST"))
+            .and(header("X-Postmark-Account-Token", "account-token"))
+            .and(body_partial_json(json!({ "Name": "tenant-42", "DeliveryType": "Live" })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "ID": 42,
+                "Name": "tenant-42",
+                "ApiTokens": ["tenant-server-token"],
+                "Color": "green",
+                "SmtpApiActivated": true,
+                "RawEmailEnabled": false,
+                "DeliveryType": "Live",
+                "InboundSpamThreshold": 5,
+                "PostFirstOpenOnly": false,
+                "TrackOpens": false,
+                "TrackLinks": "None",
+                "IncludeBounceContentInHook": false
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(path("/deliverystats"))
+            .and(header("X-Postmark-Server-Token", "tenant-server-token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "InactiveMails": 0,
+                "Bounces": []
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let body = ServerBody::builder()
+            .name("tenant-42")
+            .delivery_type(DeliveryType::Live)
+            .build();
+        let server = account_client.create_server(&body).await.unwrap();
+        let sender = Email::parse(SafeEmail().fake::<String>().as_str()).unwrap();
+        let server_client = account_client.server_client(&server, sender).unwrap();
+
+        assert_ok!(server_client.delivery_stats().await);
+    }
+
+    #[tokio::test]
+    async fn created_domain_exposes_its_dns_records() {
+        let mock_server = MockServer::start().await;
+        let account_client = account_client(&mock_server.uri());
+
+        Mock::given(path("/domains"))
+            .and(method("POST"))
+            .and(header("X-Postmark-Account-Token", "account-token"))
+            .and(body_partial_json(json!({
+                "Name": "example.com",
+                "ReturnPathDomain": "pm-bounces.example.com"
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "Name": "example.com",
+                "SPFVerified": false,
+                "SPFHost": "example.com",
+                "SPFTextValue": "v=spf1 a mx include:spf.mtasv.net ~all",
+                "DKIMVerified": false,
+                "WeakDKIM": false,
+                "DKIMHost": "",
+                "DKIMTextValue": "",
+                "DKIMPendingHost": "20131031155228pm._domainkey.example.com",
+                "DKIMPendingTextValue": "k=rsa;p=MIGfMA0GCSqGSIb3DQEBAQUAA4GNADCBiQKBgQCFn",
+                "DKIMRevokedHost": "",
+                "DKIMRevokedTextValue": "",
+                "SafeToRemoveRevokedKeyFromDNS": false,
+                "DKIMUpdateStatus": "Pending",
+                "ReturnPathDomain": "pm-bounces.example.com",
+                "ReturnPathDomainVerified": false,
+                "ReturnPathDomainCNAMEValue": "pm.mtasv.net",
+                "ID": 36736
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(path("/domains/36736/verifyReturnPath"))
+            .and(method("PUT"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "Name": "example.com",
+                "DKIMVerified": false,
+                "ReturnPathDomain": "pm-bounces.example.com",
+                "ReturnPathDomainVerified": true,
+                "ReturnPathDomainCNAMEValue": "pm.mtasv.net",
+                "ID": 36736
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let body = DomainBody::builder()
+            .name("example.com")
+            .return_path_domain("pm-bounces.example.com")
+            .build();
+        let domain = account_client.create_domain(&body).await.unwrap();
+        let return_path = domain.return_path_record().unwrap();
+
+        assert_eq!(return_path.record_type, DnsRecordType::Cname);
+        assert_eq!(return_path.host, "pm-bounces.example.com");
+        assert!(!return_path.verified);
+
+        let domain = account_client.verify_domain_return_path(domain.id).await.unwrap();
+
+        assert!(domain.return_path_record().unwrap().verified);
+    }
+
+    #[tokio::test]
+    async fn sender_is_covered_by_a_verified_domain() {
+        let mock_server = MockServer::start().await;
+        let account_client = account_client(&mock_server.uri());
+
+        Mock::given(path("/senders"))
+            .and(method("GET"))
+            .and(header("X-Postmark-Account-Token", "account-token"))
+            .and(query_param("offset", "0"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "TotalCount": 1,
+                "SenderSignatures": [{
+                    "Domain": "example.com",
+                    "EmailAddress": "jp@example.com",
+                    "ReplyToEmailAddress": "",
+                    "Name": "JP Toto",
+                    "Confirmed": false,
+                    "ID": 36735
+                }]
+            })))
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+        Mock::given(path("/domains"))
+            .and(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "TotalCount": 1,
+                "Domains": [{
+                    "Name": "wildbit.com",
+                    "SPFVerified": true,
+                    "DKIMVerified": true,
+                    "WeakDKIM": false,
+                    "ReturnPathDomainVerified": false,
+                    "ID": 36736
+                }]
+            })))
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+
+        let unconfirmed = Email::parse("jp@example.com").unwrap();
+        let on_domain = Email::parse("support@wildbit.com").unwrap();
+
+        assert!(!account_client.is_sender_covered(&unconfirmed).await.unwrap());
+        assert!(account_client.is_sender_covered(&on_domain).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn sender_coverage_reads_every_page_before_falling_back_to_domains() {
+        let mock_server = MockServer::start().await;
+        let account_client = account_client(&mock_server.uri());
+        let signature = |id: i64, email: &str, confirmed: bool| {
+            json!({
+                "Domain": "example.com",
+                "EmailAddress": email,
+                "ReplyToEmailAddress": "",
+                "Name": "Example",
+                "Confirmed": confirmed,
+                "ID": id
+            })
+        };
+
+        Mock::given(path("/senders"))
+            .and(query_param("offset", "0"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "TotalCount": 3,
+                "SenderSignatures": [
+                    signature(1, "jp@example.com", false),
+                    signature(2, "info@example.com", true)
+                ]
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(path("/senders"))
+            .and(query_param("offset", "2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "TotalCount": 3,
+                "SenderSignatures": [signature(3, "billing@example.com", true)]
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(path("/domains"))
+            .and(query_param("offset", "0"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "TotalCount": 2,
+                "Domains": [{
+                    "Name": "example.com",
+                    "SPFVerified": true,
+                    "DKIMVerified": false,
+                    "WeakDKIM": false,
+                    "ReturnPathDomainVerified": false,
+                    "ID": 10
+                }]
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(path("/domains"))
+            .and(query_param("offset", "1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "TotalCount": 2,
+                "Domains": [{
+                    "Name": "wildbit.com",
+                    "SPFVerified": true,
+                    "DKIMVerified": true,
+                    "WeakDKIM": false,
+                    "ReturnPathDomainVerified": true,
+                    "ID": 11
+                }]
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let sender = Email::parse("support@wildbit.com").unwrap();
+
+        assert!(account_client.is_sender_covered(&sender).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn sender_coverage_stops_at_a_confirmed_signature() {
+        let mock_server = MockServer::start().await;
+        let account_client = account_client(&mock_server.uri());
+
+        Mock::given(path("/senders"))
+            .and(query_param("offset", "0"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "TotalCount": 2,
+                "SenderSignatures": [{
+                    "Domain": "example.com",
+                    "EmailAddress": "jp@example.com",
+                    "ReplyToEmailAddress": "",
+                    "Name": "JP Toto",
+                    "Confirmed": true,
+                    "ID": 36735
+                }]
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(path("/senders"))
+            .and(query_param("offset", "1"))
+            .respond_with(ResponseTemplate::new(500))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+        Mock::given(path("/domains"))
+            .respond_with(ResponseTemplate::new(500))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+
+        let sender = Email::parse("jp@example.com").unwrap();
+
+        assert!(account_client.is_sender_covered(&sender).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn create_sender_signature_sends_the_from_address() {
+        let mock_server = MockServer::start().await;
+        let account_client = account_client(&mock_server.uri());
+
+        Mock::given(path("/senders"))
+            .and(method("POST"))
+            .and(body_partial_json(json!({
+                "FromEmail": "jp@example.com",
+                "Name": "JP Toto"
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "Domain": "example.com",
+                "EmailAddress": "jp@example.com",
+                "ReplyToEmailAddress": "",
+                "Name": "JP Toto",
+                "Confirmed": false,
+                "ID": 36735,
+                "ConfirmationPersonalNote": null
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let body = SenderSignatureBody::builder()
+            .from_email(Email::parse("jp@example.com").unwrap())
+            .name("JP Toto")
+            .build();
+        let signature = account_client.create_sender_signature(&body).await.unwrap();
+
+        assert!(!signature.confirmed);
+        assert_eq!(signature.id, 36735);
+    }
+
+    #[tokio::test]
+    async fn outbound_stats_are_filtered_and_typed() {
+        let mock_server = MockServer::start().await;
+        let email_client = email_client(&mock_server.uri());
+
+        Mock::given(path("/stats/outbound"))
+            .and(method("GET"))
+            .and(query_param("tag", "welcome"))
+            .and(query_param("messagestream", "outbound"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "Sent": 615,
+                "Bounced": 64,
+                "SMTPApiErrors": 25,
+                "BounceRate": 10.406,
+                "SpamComplaints": 10,
+                "SpamComplaintsRate": 1.626,
+                "Opens": 166,
+                "UniqueOpens": 26,
+                "Tracked": 111,
+                "WithLinkTracking": 90,
+                "WithOpenTracking": 51,
+                "TotalTrackedLinksSent": 60,
+                "UniqueLinksClicked": 30,
+                "TotalClicks": 72,
+                "WithClientRecorded": 14,
+                "WithPlatformRecorded": 10,
+                "WithReadTimeRecorded": 10
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(path("/stats/outbound/opens/platforms"))
+            .and(method("GET"))
+            .and(query_param("fromdate", "2014-01-01"))
+            .and(query_param("todate", "2014-01-31"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "Days": [
+                    { "Date": "2014-01-01", "Desktop": 1, "WebMail": 1 },
+                    { "Date": "2014-01-02", "Mobile": 2, "Unknown": 1 }
+                ],
+                "Desktop": 1,
+                "Mobile": 2,
+                "Unknown": 1,
+                "WebMail": 1
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let filter = StatsFilter::new().tag("welcome").message_stream("outbound");
+        let overview = email_client.outbound_overview(&filter).await.unwrap();
+
+        assert_eq!(overview.sent, 615);
+        assert_eq!(overview.smtp_api_errors, 25);
+
+        let filter = StatsFilter::new().from_date("2014-01-01").to_date("2014-01-31");
+        let platforms = email_client.open_platform_usage(&filter).await.unwrap();
+
+        assert_eq!(platforms.days[1].counts.mobile, 2);
+        assert_eq!(platforms.days[1].counts.desktop, 0);
+        assert_eq!(platforms.totals.web_mail, 1);
+    }
+
     struct SendEmailBodyMatcher;
 
     impl wiremock::Match for SendEmailBodyMatcher {